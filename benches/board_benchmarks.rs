@@ -0,0 +1,125 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use rust_go::domain::go_board::{GoBoard, GoPlayer};
+use rust_go::domain::go_game::{GoGame, GoMove};
+use rust_go::domain::util::group_liberties_aggregator::GroupLibertiesAggregator;
+use rust_go::use_cases::place_piece::place_piece_input::PlacePieceInput;
+use rust_go::use_cases::place_piece::place_piece_requester::PlacePieceRequester;
+
+/// A fixed sequence of pseudo-random points so every run plays the same game.
+fn random_points(count: usize, size: u32) -> Vec<(u32, u32)> {
+    let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+    (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            ((state % size as u64) as u32, ((state >> 32) % size as u64) as u32)
+        })
+        .collect()
+}
+
+fn crowded_board() -> GoBoard {
+    let mut board = GoBoard::new(19);
+    for (index, (row, col)) in random_points(300, 19).into_iter().enumerate() {
+        let piece = if index % 2 == 0 { GoPlayer::BLACK } else { GoPlayer::WHITE };
+        board.place(row, col, &piece);
+    }
+
+    board
+}
+
+fn capture_check(c: &mut Criterion) {
+    let board = crowded_board();
+    let mut group = c.benchmark_group("capture_check_19x19");
+
+    group.bench_function("aggregator", |b| {
+        b.iter(|| {
+            GroupLibertiesAggregator::get_group_liberties(black_box(&board))[&GoPlayer::WHITE]
+                .iter()
+                .filter(|(_, liberties)| liberties.is_empty())
+                .count()
+        })
+    });
+
+    // What place_piece looks at now: only the chains next to the new stone.
+    let (row, col) = board
+        .empty_points()
+        .points()
+        .into_iter()
+        .find(|(row, col)| {
+            GroupLibertiesAggregator::get_adjacent_points(&board, *row, *col)
+                .iter()
+                .any(|(adjacent_row, adjacent_col)| {
+                    board.stones(&GoPlayer::WHITE).contains(*adjacent_row, *adjacent_col)
+                })
+        })
+        .unwrap();
+
+    group.bench_function("tracked_adjacent_chains", |b| {
+        b.iter(|| {
+            let board = black_box(&board);
+            GroupLibertiesAggregator::get_adjacent_points(board, row, col)
+                .into_iter()
+                .filter_map(|(row, col)| board.chain_at(row, col))
+                .filter(|chain| chain.player == GoPlayer::WHITE && chain.liberty_count() <= 1)
+                .count()
+        })
+    });
+
+    group.bench_function("bitboard_whole_board", |b| {
+        b.iter(|| {
+            let board = black_box(&board);
+            let mut remaining = *board.stones(&GoPlayer::WHITE);
+            let mut captured_chains = 0;
+            while let Some((row, col)) = remaining.first_point() {
+                let chain = board.chain_stones(row, col);
+                if board.chain_liberties(&chain).is_empty() {
+                    captured_chains += 1;
+                }
+                remaining = &remaining - &chain;
+            }
+
+            captured_chains
+        })
+    });
+
+    group.finish();
+}
+
+fn place_piece_playout(c: &mut Criterion) {
+    let points = random_points(400, 19);
+
+    c.bench_function("place_piece_playout_19x19", |b| {
+        b.iter(|| {
+            let mut game = GoGame::new(19);
+            for (row, col) in points.iter().copied() {
+                let player = game.current_player;
+                game.place_piece(PlacePieceInput { row, col, player });
+            }
+
+            game
+        })
+    });
+}
+
+fn apply_unapply(c: &mut Criterion) {
+    let mut game = GoGame::new(19);
+    for (row, col) in random_points(120, 19) {
+        let player = game.current_player;
+        game.place_piece(PlacePieceInput { row, col, player });
+    }
+    let moves: Vec<GoMove> = game.legal_moves().into_iter().collect();
+
+    c.bench_function("apply_unapply_legal_moves_19x19", |b| {
+        b.iter(|| {
+            for chosen_move in moves.iter() {
+                game.apply(black_box(*chosen_move)).unwrap();
+                game.unapply();
+            }
+        })
+    });
+}
+
+criterion_group!(benches, capture_check, place_piece_playout, apply_unapply);
+criterion_main!(benches);
@@ -1,3 +1,10 @@
+pub mod bitboard;
+pub mod chain_tracker;
+pub mod game_config;
+pub mod game_status;
 pub mod go_board;
 pub mod go_game;
+pub mod handicap;
+pub mod scoring;
+pub mod setup;
 pub mod util;
@@ -0,0 +1,293 @@
+use std::ops::{BitAnd, BitOr, Sub};
+
+use super::go_board::MAX_BOARD_SIZE;
+
+const MAX_ROWS: usize = MAX_BOARD_SIZE as usize;
+
+/// A set of points on a `rows` x `cols` board stored as one `u64` per row,
+/// bit `col` standing for the point in that column. Boards are at most 52
+/// points wide so a row always fits in a word, which makes horizontal
+/// neighbours a shift within the row and vertical neighbours the rows
+/// above and below. The rows live inline so bitboards never allocate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bitboard {
+    rows: u32,
+    cols: u32,
+    row_bits: [u64; MAX_ROWS],
+}
+
+impl Bitboard {
+    /// Panics if either side is longer than `MAX_BOARD_SIZE`, which GoBoard
+    /// never creates.
+    pub fn new(rows: u32, cols: u32) -> Self {
+        assert!(
+            rows <= MAX_BOARD_SIZE && cols <= MAX_BOARD_SIZE,
+            "A {}x{} bitboard is larger than {}x{}.", rows, cols, MAX_BOARD_SIZE, MAX_BOARD_SIZE
+        );
+
+        Self {
+            rows,
+            cols,
+            row_bits: [0; MAX_ROWS],
+        }
+    }
+
+    /// A bitboard with every point of the board set.
+    pub fn full(rows: u32, cols: u32) -> Self {
+        let mut bitboard = Self::new(rows, cols);
+        let row_mask = bitboard.row_mask();
+        for bits in bitboard.used_rows_mut() {
+            *bits = row_mask;
+        }
+
+        bitboard
+    }
+
+    pub fn from_points(rows: u32, cols: u32, points: &[(u32, u32)]) -> Self {
+        let mut bitboard = Self::new(rows, cols);
+        for (row, col) in points {
+            bitboard.insert(*row, *col);
+        }
+
+        bitboard
+    }
+
+    pub fn contains(&self, row: u32, col: u32) -> bool {
+        self.on_board(row, col) && self.row_bits[row as usize] & (1 << col) != 0
+    }
+
+    /// Sets the point, returning whether it was unset before. Points outside
+    /// the board are ignored.
+    pub fn insert(&mut self, row: u32, col: u32) -> bool {
+        if !self.on_board(row, col) {
+            return false;
+        }
+
+        let was_unset = !self.contains(row, col);
+        self.row_bits[row as usize] |= 1 << col;
+
+        was_unset
+    }
+
+    pub fn remove(&mut self, row: u32, col: u32) {
+        if self.on_board(row, col) {
+            self.row_bits[row as usize] &= !(1 << col);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.row_bits = [0; MAX_ROWS];
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.used_rows().iter().all(|bits| *bits == 0)
+    }
+
+    pub fn count(&self) -> u32 {
+        self.used_rows().iter().map(|bits| bits.count_ones()).sum()
+    }
+
+    /// The first set point in row-major order.
+    pub fn first_point(&self) -> Option<(u32, u32)> {
+        let (row, bits) = self.used_rows().iter().enumerate().find(|(_, bits)| **bits != 0)?;
+
+        Some((row as u32, bits.trailing_zeros()))
+    }
+
+    /// The set points in row-major order.
+    pub fn points(&self) -> Vec<(u32, u32)> {
+        let mut points = Vec::with_capacity(self.count() as usize);
+        for (row, bits) in self.used_rows().iter().enumerate() {
+            let mut remaining = *bits;
+            while remaining != 0 {
+                points.push((row as u32, remaining.trailing_zeros()));
+                remaining &= remaining - 1;
+            }
+        }
+
+        points
+    }
+
+    /// Every point orthogonally adjacent to a set point, excluding the set
+    /// points themselves unless they neighbour each other.
+    pub fn neighbours(&self) -> Self {
+        let row_mask = self.row_mask();
+        let used_rows = self.used_rows();
+
+        let mut neighbours = Self::new(self.rows, self.cols);
+        for (row, bits) in used_rows.iter().enumerate() {
+            let mut adjacent = (bits << 1) | (bits >> 1);
+            if row > 0 {
+                adjacent |= used_rows[row - 1];
+            }
+            if row + 1 < used_rows.len() {
+                adjacent |= used_rows[row + 1];
+            }
+            neighbours.row_bits[row] = adjacent & row_mask;
+        }
+
+        neighbours
+    }
+
+    /// Grows the set one step at a time through the points of `within` until
+    /// nothing more is reachable, which gives the chain containing the
+    /// starting points.
+    pub fn flood_fill(&self, within: &Self) -> Self {
+        let mut filled = self & within;
+        loop {
+            let grown = &(&filled | &filled.neighbours()) & within;
+            if grown == filled {
+                return filled;
+            }
+            filled = grown;
+        }
+    }
+
+    /// Splits the set into its orthogonally connected parts, ordered by
+    /// their first point.
+    pub fn components(&self) -> Vec<Self> {
+        let mut components = Vec::new();
+        let mut remaining = *self;
+        while let Some((row, col)) = remaining.first_point() {
+            let component = Self::from_points(self.rows, self.cols, &[(row, col)]).flood_fill(&remaining);
+            remaining = &remaining - &component;
+            components.push(component);
+        }
+
+        components
+    }
+
+    fn on_board(&self, row: u32, col: u32) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    fn row_mask(&self) -> u64 {
+        (1 << self.cols) - 1
+    }
+
+    fn used_rows(&self) -> &[u64] {
+        &self.row_bits[..self.rows as usize]
+    }
+
+    fn used_rows_mut(&mut self) -> &mut [u64] {
+        &mut self.row_bits[..self.rows as usize]
+    }
+
+    fn combine(&self, other: &Self, operation: impl Fn(u64, u64) -> u64) -> Self {
+        debug_assert_eq!((self.rows, self.cols), (other.rows, other.cols));
+
+        let mut combined = *self;
+        for (bits, other_bits) in combined.used_rows_mut().iter_mut().zip(other.used_rows()) {
+            *bits = operation(*bits, *other_bits);
+        }
+
+        combined
+    }
+}
+
+impl BitAnd for &Bitboard {
+    type Output = Bitboard;
+
+    fn bitand(self, other: &Bitboard) -> Bitboard {
+        self.combine(other, |bits, other_bits| bits & other_bits)
+    }
+}
+
+impl BitOr for &Bitboard {
+    type Output = Bitboard;
+
+    fn bitor(self, other: &Bitboard) -> Bitboard {
+        self.combine(other, |bits, other_bits| bits | other_bits)
+    }
+}
+
+impl Sub for &Bitboard {
+    type Output = Bitboard;
+
+    fn sub(self, other: &Bitboard) -> Bitboard {
+        self.combine(other, |bits, other_bits| bits & !other_bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut bitboard = Bitboard::new(9, 13);
+
+        assert!(bitboard.insert(0, 0));
+        assert!(!bitboard.insert(0, 0));
+        assert!(bitboard.insert(8, 12));
+        assert!(!bitboard.insert(9, 0));
+        assert!(!bitboard.insert(0, 13));
+
+        assert!(bitboard.contains(0, 0));
+        assert!(bitboard.contains(8, 12));
+        assert!(!bitboard.contains(12, 8));
+        assert_eq!(2, bitboard.count());
+        assert_eq!(vec![(0, 0), (8, 12)], bitboard.points());
+        assert_eq!(Some((0, 0)), bitboard.first_point());
+
+        bitboard.remove(0, 0);
+        bitboard.remove(20, 20);
+        assert_eq!(vec![(8, 12)], bitboard.points());
+
+        assert_eq!(Some((8, 12)), bitboard.first_point());
+
+        bitboard.clear();
+        assert!(bitboard.is_empty());
+        assert_eq!(None, bitboard.first_point());
+    }
+
+    #[test]
+    fn test_neighbours_stay_on_the_board() {
+        //Corners and edges of a rectangular board
+        let bitboard = Bitboard::from_points(9, 13, &[(0, 0), (4, 12), (8, 6)]);
+
+        assert_eq!(
+            Bitboard::from_points(
+                9,
+                13,
+                &[(0, 1), (1, 0), (3, 12), (5, 12), (4, 11), (7, 6), (8, 5), (8, 7)]
+            ),
+            bitboard.neighbours()
+        );
+
+        let full = Bitboard::full(19, 19);
+        assert_eq!(full, full.neighbours());
+        assert_eq!(361, full.count());
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        //|X|X| | |
+        //| |X| |X|
+        //|X| | |X|
+
+        let stones = Bitboard::from_points(3, 4, &[(0, 0), (0, 1), (1, 1), (1, 3), (2, 0), (2, 3)]);
+
+        let chain = Bitboard::from_points(3, 4, &[(1, 1)]).flood_fill(&stones);
+        assert_eq!(vec![(0, 0), (0, 1), (1, 1)], chain.points());
+
+        let chain = Bitboard::from_points(3, 4, &[(2, 3)]).flood_fill(&stones);
+        assert_eq!(vec![(1, 3), (2, 3)], chain.points());
+
+        let empty = &Bitboard::full(3, 4) - &stones;
+        assert_eq!(vec![(0, 3), (1, 2), (2, 2)], (&chain.neighbours() & &empty).points());
+    }
+
+    #[test]
+    fn test_components() {
+        //|X|X| | |
+        //| |X| |X|
+        //|X| | |X|
+
+        let stones = Bitboard::from_points(3, 4, &[(0, 0), (0, 1), (1, 1), (1, 3), (2, 0), (2, 3)]);
+
+        let components: Vec<Vec<(u32, u32)>> = stones.components().iter().map(Bitboard::points).collect();
+        assert_eq!(vec![vec![(0, 0), (0, 1), (1, 1)], vec![(1, 3), (2, 3)], vec![(2, 0)]], components);
+        assert!(Bitboard::new(3, 4).components().is_empty());
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+
+use super::bitboard::Bitboard;
+use super::go_board::GoPlayer;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chain {
+    pub player: GoPlayer,
+    pub stones: Bitboard,
+    pub liberties: Bitboard,
+    /// Kept alongside `liberties` so that reading it doesn't count bits.
+    liberty_count: u32,
+}
+
+impl Chain {
+    fn new(player: GoPlayer, stones: Bitboard, liberties: Bitboard) -> Self {
+        Self {
+            player,
+            stones,
+            liberties,
+            liberty_count: liberties.count(),
+        }
+    }
+
+    pub fn liberty_count(&self) -> u32 {
+        self.liberty_count
+    }
+
+    fn set_liberties(&mut self, liberties: Bitboard) {
+        self.liberties = liberties;
+        self.liberty_count = liberties.count();
+    }
+
+    fn remove_liberty(&mut self, row: u32, col: u32) {
+        if self.liberties.contains(row, col) {
+            self.liberties.remove(row, col);
+            self.liberty_count -= 1;
+        }
+    }
+}
+
+/// Keeps every chain on the board up to date as stones come and go, so
+/// that finding a stone's chain and its liberties doesn't need a walk over
+/// the board. Stones are joined with a union-find over the points, the root
+/// of each set holding the chain's stones and liberties. Each chain caches
+/// its liberty count, which is recounted only when its liberties are
+/// recomputed on a merge, split or capture.
+///
+/// Placing a stone only merges it with the neighbouring chains of its
+/// colour. Taking a single stone off can split its chain, so the rest of
+/// that chain is rebuilt from its stones.
+#[derive(Debug, Clone)]
+pub struct ChainTracker {
+    rows: u32,
+    cols: u32,
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    chains: HashMap<usize, Chain>,
+}
+
+impl ChainTracker {
+    pub fn new(rows: u32, cols: u32) -> Self {
+        let points = rows as usize * cols as usize;
+
+        Self {
+            rows,
+            cols,
+            parent: (0..points).collect(),
+            rank: vec![0; points],
+            chains: HashMap::new(),
+        }
+    }
+
+    pub fn chain_at(&self, row: u32, col: u32) -> Option<&Chain> {
+        let root = self.root_at(row, col)?;
+        self.chains.get(&root)
+    }
+
+    pub fn chains(&self) -> impl Iterator<Item = &Chain> {
+        self.chains.values()
+    }
+
+    /// Adds a stone that has just been put on the board; `empty_points` is
+    /// the board's empty points after placing it.
+    pub fn place(&mut self, row: u32, col: u32, player: GoPlayer, empty_points: &Bitboard) {
+        let index = match self.index(row, col) {
+            Some(index) => index,
+            None => return,
+        };
+
+        let stone = Bitboard::from_points(self.rows, self.cols, &[(row, col)]);
+        self.parent[index] = index;
+        self.rank[index] = 0;
+        self.chains.insert(index, Chain::new(player, stone, &stone.neighbours() & empty_points));
+
+        for (adjacent_row, adjacent_col) in self.adjacent_points(row, col) {
+            if let Some(adjacent_root) = self.root_at(adjacent_row, adjacent_col) {
+                let adjacent_chain = self.chains.get_mut(&adjacent_root).unwrap();
+                adjacent_chain.remove_liberty(row, col);
+
+                if adjacent_chain.player == player {
+                    let root = self.find(index);
+                    self.union(root, adjacent_root);
+                }
+            }
+        }
+    }
+
+    /// Takes a single stone off after it has been removed from the board;
+    /// `empty_points` is the board's empty points after removing it.
+    pub fn remove(&mut self, row: u32, col: u32, empty_points: &Bitboard) {
+        let root = match self.root_at(row, col) {
+            Some(root) => root,
+            None => return,
+        };
+
+        let chain = self.chains.remove(&root).unwrap();
+
+        let mut remaining_stones = chain.stones;
+        remaining_stones.remove(row, col);
+        self.rebuild(remaining_stones, chain.player, empty_points);
+
+        let freed_point = Bitboard::from_points(self.rows, self.cols, &[(row, col)]);
+        self.refresh_liberties_around(&freed_point, empty_points);
+    }
+
+    /// Takes off the whole chain at (row, col) after its stones have been
+    /// removed from the board, as happens on a capture.
+    pub fn remove_chain(&mut self, row: u32, col: u32, empty_points: &Bitboard) {
+        let root = match self.root_at(row, col) {
+            Some(root) => root,
+            None => return,
+        };
+
+        let chain = self.chains.remove(&root).unwrap();
+        self.refresh_liberties_around(&chain.stones, empty_points);
+    }
+
+    fn rebuild(&mut self, mut stones: Bitboard, player: GoPlayer, empty_points: &Bitboard) {
+        while let Some((row, col)) = stones.first_point() {
+            let seed = Bitboard::from_points(self.rows, self.cols, &[(row, col)]);
+            let part = seed.flood_fill(&stones);
+
+            let root = self.index(row, col).unwrap();
+            for (part_row, part_col) in part.points() {
+                let index = self.index(part_row, part_col).unwrap();
+                self.parent[index] = root;
+                self.rank[index] = 0;
+            }
+            self.rank[root] = 1;
+
+            self.chains.insert(root, Chain::new(player, part, &part.neighbours() & empty_points));
+
+            stones = &stones - &part;
+        }
+    }
+
+    fn refresh_liberties_around(&mut self, points: &Bitboard, empty_points: &Bitboard) {
+        let mut refreshed = Vec::new();
+        for (row, col) in points.neighbours().points() {
+            if let Some(root) = self.root_at(row, col) {
+                if !refreshed.contains(&root) {
+                    let chain = self.chains.get_mut(&root).unwrap();
+                    chain.set_liberties(&chain.stones.neighbours() & empty_points);
+                    refreshed.push(root);
+                }
+            }
+        }
+    }
+
+    fn union(&mut self, first_root: usize, second_root: usize) {
+        if first_root == second_root {
+            return;
+        }
+
+        let (root, child) = match self.rank[first_root] < self.rank[second_root] {
+            true => (second_root, first_root),
+            false => (first_root, second_root),
+        };
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.parent[child] = root;
+
+        let child_chain = self.chains.remove(&child).unwrap();
+        let chain = self.chains.get_mut(&root).unwrap();
+        chain.stones = &chain.stones | &child_chain.stones;
+        chain.set_liberties(&(&chain.liberties | &child_chain.liberties) - &chain.stones);
+    }
+
+    /// The root of the chain holding the stone at (row, col). Empty points
+    /// can still point at the root of a chain that was taken off, so the
+    /// chain found has to actually hold the point.
+    fn root_at(&self, row: u32, col: u32) -> Option<usize> {
+        let root = self.find(self.index(row, col)?);
+
+        match self.chains.get(&root) {
+            Some(chain) if chain.stones.contains(row, col) => Some(root),
+            _ => None,
+        }
+    }
+
+    // Union by rank keeps the trees shallow enough to walk without path
+    // compression, which lets lookups borrow the tracker immutably.
+    fn find(&self, mut index: usize) -> usize {
+        while self.parent[index] != index {
+            index = self.parent[index];
+        }
+
+        index
+    }
+
+    fn adjacent_points(&self, row: u32, col: u32) -> Vec<(u32, u32)> {
+        let mut adjacent_points = Vec::with_capacity(4);
+
+        if row > 0 {
+            adjacent_points.push((row - 1, col));
+        }
+        if row + 1 < self.rows {
+            adjacent_points.push((row + 1, col));
+        }
+        if col > 0 {
+            adjacent_points.push((row, col - 1));
+        }
+        if col + 1 < self.cols {
+            adjacent_points.push((row, col + 1));
+        }
+
+        adjacent_points
+    }
+
+    fn index(&self, row: u32, col: u32) -> Option<usize> {
+        match row < self.rows && col < self.cols {
+            true => Some(row as usize * self.cols as usize + col as usize),
+            false => None,
+        }
+    }
+}
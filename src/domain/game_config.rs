@@ -0,0 +1,221 @@
+use std::error::Error;
+use std::fmt;
+
+use super::go_board::BoardSizeError;
+use super::go_game::KoRule;
+use super::handicap::{HandicapError, HandicapKomi, HandicapPlacement};
+use super::scoring::ScoringMethod;
+
+/// The rules a game is played under. The presets follow each ruleset's
+/// usual komi and how it compensates White in handicap games; Ing's
+/// rules, where Black wins ties on 8 points, are given as 7.5.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ruleset {
+    pub name: String,
+    pub komi: f32,
+    pub ko_rule: KoRule,
+    pub suicide_allowed: bool,
+    pub scoring_method: ScoringMethod,
+    pub handicap_komi: HandicapKomi,
+    /// Passing hands the opponent a prisoner, as the AGA rules have it, so
+    /// that area and territory scoring give the same result.
+    pub pass_gives_prisoner: bool
+}
+
+/// The rules of a game set up without a config: no komi, simple ko and
+/// territory scoring. They have no name, so no rules are recorded for a
+/// game played by them.
+impl Default for Ruleset {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            komi: 0.0,
+            ko_rule: KoRule::Simple,
+            suicide_allowed: false,
+            scoring_method: ScoringMethod::Territory,
+            handicap_komi: HandicapKomi::HalfPoint,
+            pass_gives_prisoner: false
+        }
+    }
+}
+
+impl Ruleset {
+    pub fn chinese() -> Self {
+        Self {
+            name: String::from("Chinese"),
+            komi: 7.5,
+            ko_rule: KoRule::PositionalSuperko,
+            suicide_allowed: false,
+            scoring_method: ScoringMethod::Area,
+            handicap_komi: HandicapKomi::PerStone,
+            pass_gives_prisoner: false
+        }
+    }
+
+    pub fn japanese() -> Self {
+        Self {
+            name: String::from("Japanese"),
+            komi: 6.5,
+            ko_rule: KoRule::Simple,
+            suicide_allowed: false,
+            scoring_method: ScoringMethod::Territory,
+            handicap_komi: HandicapKomi::HalfPoint,
+            pass_gives_prisoner: false
+        }
+    }
+
+    pub fn korean() -> Self {
+        Self {
+            name: String::from("Korean"),
+            ..Self::japanese()
+        }
+    }
+
+    pub fn aga() -> Self {
+        Self {
+            name: String::from("AGA"),
+            komi: 7.5,
+            ko_rule: KoRule::SituationalSuperko,
+            suicide_allowed: false,
+            scoring_method: ScoringMethod::Area,
+            handicap_komi: HandicapKomi::PerStoneAfterFirst,
+            pass_gives_prisoner: true
+        }
+    }
+
+    pub fn new_zealand() -> Self {
+        Self {
+            name: String::from("NZ"),
+            komi: 7.0,
+            ko_rule: KoRule::SituationalSuperko,
+            suicide_allowed: true,
+            scoring_method: ScoringMethod::Area,
+            handicap_komi: HandicapKomi::HalfPoint,
+            pass_gives_prisoner: false
+        }
+    }
+
+    pub fn ing() -> Self {
+        Self {
+            name: String::from("GOE"),
+            komi: 7.5,
+            ko_rule: KoRule::SituationalSuperko,
+            suicide_allowed: true,
+            scoring_method: ScoringMethod::Area,
+            handicap_komi: HandicapKomi::PerStone,
+            pass_gives_prisoner: false
+        }
+    }
+
+    pub fn tromp_taylor() -> Self {
+        Self {
+            name: String::from("Tromp-Taylor"),
+            komi: 7.5,
+            ko_rule: KoRule::PositionalSuperko,
+            suicide_allowed: true,
+            scoring_method: ScoringMethod::Area,
+            handicap_komi: HandicapKomi::PerStone,
+            pass_gives_prisoner: false
+        }
+    }
+
+    /// The preset named by an SGF RU property, ignoring case. Besides the
+    /// names SGF lists (AGA, GOE, Japanese and NZ) the other presets'
+    /// names are accepted too.
+    pub fn from_sgf_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "chinese" => Some(Self::chinese()),
+            "japanese" => Some(Self::japanese()),
+            "korean" => Some(Self::korean()),
+            "aga" => Some(Self::aga()),
+            "nz" | "new zealand" => Some(Self::new_zealand()),
+            "goe" | "ing" => Some(Self::ing()),
+            "tromp-taylor" | "tromp taylor" => Some(Self::tromp_taylor()),
+            _ => None
+        }
+    }
+}
+
+/// Everything needed to set up a new game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameConfig {
+    pub rows: u32,
+    pub cols: u32,
+    pub ruleset: Ruleset,
+    pub handicap: Option<HandicapPlacement>,
+    /// Replaces the komi the ruleset would give, handicap included.
+    pub komi: Option<f32>
+}
+
+impl GameConfig {
+    pub fn new(size: u32, ruleset: Ruleset) -> Self {
+        Self {
+            rows: size,
+            cols: size,
+            ruleset,
+            handicap: None,
+            komi: None
+        }
+    }
+
+    /// An even game on a square board by the default rules, which is what
+    /// `GoGame::new` starts.
+    pub fn default_for(size: u32) -> Self {
+        Self::new(size, Ruleset::default())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameConfigError {
+    BoardSize(BoardSizeError),
+    Handicap(HandicapError)
+}
+
+impl fmt::Display for GameConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameConfigError::BoardSize(error) => error.fmt(f),
+            GameConfigError::Handicap(error) => error.fmt(f)
+        }
+    }
+}
+
+impl Error for GameConfigError {}
+
+impl From<BoardSizeError> for GameConfigError {
+    fn from(error: BoardSizeError) -> Self {
+        GameConfigError::BoardSize(error)
+    }
+}
+
+impl From<HandicapError> for GameConfigError {
+    fn from(error: HandicapError) -> Self {
+        GameConfigError::Handicap(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_from_sgf_names() {
+        assert_eq!(Some(Ruleset::japanese()), Ruleset::from_sgf_name("Japanese"));
+        assert_eq!(Some(Ruleset::new_zealand()), Ruleset::from_sgf_name("NZ"));
+        assert_eq!(Some(Ruleset::ing()), Ruleset::from_sgf_name("goe"));
+        assert_eq!(Some(Ruleset::tromp_taylor()), Ruleset::from_sgf_name(" Tromp-Taylor "));
+        assert_eq!(None, Ruleset::from_sgf_name("Pandanet"));
+
+        for ruleset in [
+            Ruleset::chinese(),
+            Ruleset::japanese(),
+            Ruleset::korean(),
+            Ruleset::aga(),
+            Ruleset::new_zealand(),
+            Ruleset::ing(),
+            Ruleset::tromp_taylor()
+        ] {
+            assert_eq!(Some(ruleset.clone()), Ruleset::from_sgf_name(&ruleset.name));
+        }
+    }
+}
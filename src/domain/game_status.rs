@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use super::go_board::GoPlayer;
+use super::scoring::ScoreResult;
+
+/// How a finished game was decided.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameResult {
+    Resignation { winner: GoPlayer },
+    Score { winner: GoPlayer, margin: f32 },
+    Draw
+}
+
+impl GameResult {
+    pub fn from_score(score: &ScoreResult) -> Self {
+        match score.winner {
+            Some(winner) => GameResult::Score { winner, margin: score.margin },
+            None => GameResult::Draw
+        }
+    }
+
+    pub fn winner(&self) -> Option<GoPlayer> {
+        match self {
+            GameResult::Resignation { winner } | GameResult::Score { winner, .. } => Some(*winner),
+            GameResult::Draw => None
+        }
+    }
+}
+
+/// Writes the result the way SGF's RE property does: "B+R", "W+3.5" and
+/// "0" for a draw.
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let colour = |winner: &GoPlayer| match winner {
+            GoPlayer::BLACK => "B",
+            GoPlayer::WHITE => "W"
+        };
+
+        match self {
+            GameResult::Resignation { winner } => write!(f, "{}+R", colour(winner)),
+            GameResult::Score { winner, margin } => write!(f, "{}+{}", colour(winner), margin),
+            GameResult::Draw => write!(f, "0")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum GameStatus {
+    #[default]
+    InProgress,
+    /// Both players passed in a row, so no more moves are played and the
+    /// game waits to be scored.
+    Scoring,
+    Finished { result: GameResult }
+}
+
+/// The stones marked dead while a game is being scored and the players
+/// who have accepted them. Any change to the marking withdraws both
+/// acceptances.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeadStoneMarking {
+    pub dead_stones: HashSet<(u32, u32)>,
+    pub accepted_by: HashSet<GoPlayer>
+}
+
+impl DeadStoneMarking {
+    /// Marks the group dead, or alive again if it is already marked dead.
+    pub fn toggle_group(&mut self, group: &HashSet<(u32, u32)>) {
+        if group.iter().all(|point| self.dead_stones.contains(point)) {
+            self.dead_stones.retain(|point| !group.contains(point));
+        } else {
+            self.dead_stones.extend(group.iter().copied());
+        }
+        self.accepted_by.clear();
+    }
+
+    pub fn accept(&mut self, player: GoPlayer) {
+        self.accepted_by.insert(player);
+    }
+
+    pub fn accepted_by_both(&self) -> bool {
+        self.accepted_by.len() == 2
+    }
+
+    /// The dead stones in row-major order.
+    pub fn sorted_dead_stones(&self) -> Vec<(u32, u32)> {
+        let mut dead_stones: Vec<(u32, u32)> = self.dead_stones.iter().copied().collect();
+        dead_stones.sort();
+
+        dead_stones
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_result_notation() {
+        assert_eq!("B+R", GameResult::Resignation { winner: GoPlayer::BLACK }.to_string());
+        assert_eq!("W+3.5", GameResult::Score { winner: GoPlayer::WHITE, margin: 3.5 }.to_string());
+        assert_eq!("B+12", GameResult::Score { winner: GoPlayer::BLACK, margin: 12.0 }.to_string());
+        assert_eq!("0", GameResult::Draw.to_string());
+    }
+
+    #[test]
+    fn test_toggle_group_withdraws_acceptance() {
+        let mut marking = DeadStoneMarking::default();
+        let group = HashSet::from([(0, 0), (0, 1)]);
+
+        marking.accept(GoPlayer::BLACK);
+        marking.toggle_group(&group);
+        assert_eq!(vec![(0, 0), (0, 1)], marking.sorted_dead_stones());
+        assert!(marking.accepted_by.is_empty());
+
+        marking.accept(GoPlayer::BLACK);
+        marking.accept(GoPlayer::WHITE);
+        assert!(marking.accepted_by_both());
+
+        marking.toggle_group(&group);
+        assert!(marking.dead_stones.is_empty());
+        assert!(!marking.accepted_by_both());
+    }
+
+    #[test]
+    fn test_result_from_score() {
+        let result = GameResult::from_score(&ScoreResult::new(40.0, 43.5));
+        assert_eq!(GameResult::Score { winner: GoPlayer::WHITE, margin: 3.5 }, result);
+        assert_eq!(Some(GoPlayer::WHITE), result.winner());
+
+        let result = GameResult::from_score(&ScoreResult::new(40.0, 40.0));
+        assert_eq!(GameResult::Draw, result);
+        assert_eq!(None, result.winner());
+    }
+}
@@ -1,56 +1,375 @@
+use std::error::Error;
+use std::fmt;
+
+use super::bitboard::Bitboard;
+use super::chain_tracker::{Chain, ChainTracker};
+use super::util::group_liberties_aggregator::{Group, Liberties};
+
 pub type GoBoardState = Vec<Vec<Option<GoPlayer>>>;
 
+/// The largest side length a board can have, matching the 52 point
+/// letters SGF has for coordinates.
+pub const MAX_BOARD_SIZE: u32 = 52;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::upper_case_acronyms)]
 pub enum GoPlayer {
     BLACK,
     WHITE,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBoundsError {
+    pub row: u32,
+    pub col: u32,
+}
+
+impl fmt::Display for OutOfBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The point ({}, {}) is outside the board.", self.row, self.col)
+    }
+}
+
+impl Error for OutOfBoundsError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoardSizeError {
+    pub rows: u32,
+    pub cols: u32,
+}
+
+impl fmt::Display for BoardSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "A {}x{} board is not supported, both sides must be between 1 and {}.",
+            self.rows, self.cols, MAX_BOARD_SIZE)
+    }
+}
+
+impl Error for BoardSizeError {}
+
+/// The stones are kept several ways: `board_state` gives callers a row by
+/// row view, the per-colour bitboards back set operations and the chain
+/// tracker follows every chain and its liberties. They are only kept in
+/// sync by `place`, `remove`, `remove_chain` and `clear`, so none of them
+/// can be written to directly.
+#[derive(Debug, Clone)]
 pub struct GoBoard {
-    pub board_state: GoBoardState,
+    board_state: GoBoardState,
+    black_stones: Bitboard,
+    white_stones: Bitboard,
+    chains: ChainTracker,
+    hash: u64,
+}
+
+// Everything else is derived from the stones, and the chain tracker's
+// internal layout depends on the order the stones were played in.
+impl PartialEq for GoBoard {
+    fn eq(&self, other: &Self) -> bool {
+        self.board_state == other.board_state
+    }
 }
 
 impl GoBoard {
+    /// Creates a square board, panicking on the sizes `with_dimensions`
+    /// rejects.
     pub fn new(size: u32) -> Self {
+        Self::with_dimensions(size, size).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Creates a board with `rows` rows of `cols` points each, rejecting
+    /// empty boards and sides longer than `MAX_BOARD_SIZE`.
+    pub fn with_dimensions(rows: u32, cols: u32) -> Result<Self, BoardSizeError> {
+        let valid_sizes = 1..=MAX_BOARD_SIZE;
+        if !valid_sizes.contains(&rows) || !valid_sizes.contains(&cols) {
+            return Err(BoardSizeError { rows, cols });
+        }
+
+        Ok(Self::empty(rows, cols))
+    }
+
+    fn empty(rows: u32, cols: u32) -> Self {
         Self {
-            board_state: vec![vec![None; size as usize]; size as usize],
+            board_state: vec![vec![None; cols as usize]; rows as usize],
+            black_stones: Bitboard::new(rows, cols),
+            white_stones: Bitboard::new(rows, cols),
+            chains: ChainTracker::new(rows, cols),
+            hash: 0,
+        }
+    }
+
+    pub fn board_state(&self) -> &GoBoardState {
+        &self.board_state
+    }
+
+    pub fn rows(&self) -> u32 {
+        self.board_state.len() as u32
+    }
+
+    pub fn cols(&self) -> u32 {
+        self.board_state.first().map_or(0, |cols| cols.len()) as u32
+    }
+
+    pub fn check_bounds(&self, row: u32, col: u32) -> Result<(), OutOfBoundsError> {
+        match self.board_state.get(row as usize).and_then(|cols| cols.get(col as usize)) {
+            Some(_) => Ok(()),
+            None => Err(OutOfBoundsError { row, col }),
         }
     }
 
+    pub fn get(&self, row: u32, col: u32) -> Result<Option<GoPlayer>, OutOfBoundsError> {
+        self.check_bounds(row, col)?;
+
+        Ok(self.board_state[row as usize][col as usize])
+    }
+
     pub fn place(&mut self, row: u32, col: u32, piece: &GoPlayer) -> bool {
-        match self.board_state[row as usize][col as usize] {
-            Some(_) => false,
-            None => {
+        match self.get(row, col) {
+            Ok(None) => {
                 self.board_state[row as usize][col as usize] = Some(*piece);
+                self.stones_mut(piece).insert(row, col);
+                self.hash ^= Self::zobrist_key(row, col, piece);
+
+                let empty_points = self.empty_points();
+                self.chains.place(row, col, *piece, &empty_points);
                 true
             }
+            _ => false,
         }
     }
 
     pub fn remove(&mut self, row: u32, col: u32) {
-        self.board_state[row as usize][col as usize] = None;
+        if let Ok(Some(piece)) = self.get(row, col) {
+            self.board_state[row as usize][col as usize] = None;
+            self.stones_mut(&piece).remove(row, col);
+            self.hash ^= Self::zobrist_key(row, col, &piece);
+
+            let empty_points = self.empty_points();
+            self.chains.remove(row, col, &empty_points);
+        }
+    }
+
+    /// Takes off every stone of the chain at (row, col) and returns them in
+    /// row-major order.
+    pub fn remove_chain(&mut self, row: u32, col: u32) -> Vec<(u32, u32)> {
+        let (player, stones) = match self.chains.chain_at(row, col) {
+            Some(chain) => (chain.player, chain.stones.points()),
+            None => return Vec::new(),
+        };
+
+        for (stone_row, stone_col) in stones.iter().copied() {
+            self.board_state[stone_row as usize][stone_col as usize] = None;
+            self.stones_mut(&player).remove(stone_row, stone_col);
+            self.hash ^= Self::zobrist_key(stone_row, stone_col, &player);
+        }
+
+        let empty_points = self.empty_points();
+        self.chains.remove_chain(row, col, &empty_points);
+
+        stones
     }
 
     pub fn clear(&mut self) {
-        for row in (0..self.board_state.len()) {
-            for col in (0..self.board_state[0].len()) {
-                self.board_state[row][col] = None;
+        for point in self.board_state.iter_mut().flatten() {
+            *point = None;
+        }
+        self.black_stones.clear();
+        self.white_stones.clear();
+        self.chains = ChainTracker::new(self.rows(), self.cols());
+        self.hash = 0;
+    }
+
+    pub fn stones(&self, player: &GoPlayer) -> &Bitboard {
+        match player {
+            GoPlayer::BLACK => &self.black_stones,
+            GoPlayer::WHITE => &self.white_stones,
+        }
+    }
+
+    pub fn empty_points(&self) -> Bitboard {
+        &(&Bitboard::full(self.rows(), self.cols()) - &self.black_stones) - &self.white_stones
+    }
+
+    pub fn chain_at(&self, row: u32, col: u32) -> Option<&Chain> {
+        self.chains.chain_at(row, col)
+    }
+
+    /// Every stone connected to the stone at (row, col); empty if there is
+    /// no stone there.
+    pub fn chain_stones(&self, row: u32, col: u32) -> Bitboard {
+        match self.chain_at(row, col) {
+            Some(chain) => chain.stones,
+            None => Bitboard::new(self.rows(), self.cols()),
+        }
+    }
+
+    pub fn chain_liberties(&self, chain: &Bitboard) -> Bitboard {
+        &chain.neighbours() & &self.empty_points()
+    }
+
+    /// The group of the stone at (row, col), or `None` on an empty point.
+    pub fn group_at(&self, row: u32, col: u32) -> Result<Option<Group>, OutOfBoundsError> {
+        self.check_bounds(row, col)?;
+
+        Ok(self.chain_at(row, col).map(|chain| chain.stones.points().into_iter().collect()))
+    }
+
+    /// The liberties of the group at (row, col), or `None` on an empty point.
+    pub fn liberties_at(&self, row: u32, col: u32) -> Result<Option<Liberties>, OutOfBoundsError> {
+        self.check_bounds(row, col)?;
+
+        Ok(self.chain_at(row, col).map(|chain| chain.liberties.points().into_iter().collect()))
+    }
+
+    /// Every group of `player` down to its last liberty, ordered by their
+    /// first stone in row-major order.
+    pub fn chains_in_atari(&self, player: &GoPlayer) -> Vec<Group> {
+        let mut chains = self.chains.chains()
+            .filter(|chain| chain.player == *player && chain.liberty_count() == 1)
+            .collect::<Vec<_>>();
+        chains.sort_by_key(|chain| chain.stones.first_point());
+
+        chains.into_iter().map(|chain| chain.stones.points().into_iter().collect()).collect()
+    }
+
+    /// The groups of the other colour touching the group at (row, col),
+    /// ordered by their first stone in row-major order. Empty on an empty
+    /// point.
+    pub fn adjacent_enemy_groups(&self, row: u32, col: u32) -> Result<Vec<Group>, OutOfBoundsError> {
+        self.check_bounds(row, col)?;
+
+        let chain = match self.chain_at(row, col) {
+            Some(chain) => chain,
+            None => return Ok(Vec::new()),
+        };
+
+        let enemy = match chain.player {
+            GoPlayer::BLACK => GoPlayer::WHITE,
+            GoPlayer::WHITE => GoPlayer::BLACK,
+        };
+
+        let mut enemy_stones = &chain.stones.neighbours() & self.stones(&enemy);
+        let mut groups = Vec::new();
+        while let Some((enemy_row, enemy_col)) = enemy_stones.first_point() {
+            let enemy_chain = self.chain_stones(enemy_row, enemy_col);
+            enemy_stones = &enemy_stones - &enemy_chain;
+            groups.push((enemy_chain.first_point(), enemy_chain.points().into_iter().collect()));
+        }
+        groups.sort_by_key(|(first_point, _)| *first_point);
+
+        Ok(groups.into_iter().map(|(_, group)| group).collect())
+    }
+
+    /// Zobrist hash of the stones on the board, kept up to date by `place`,
+    /// `remove` and `clear`. The empty board always hashes to 0.
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// The hash the board would have after placing `piece` at (row, col)
+    /// and taking off `removed_stones`, without changing the board. The
+    /// removed stones may include the new stone itself.
+    pub fn hash_after_placing(
+        &self,
+        row: u32,
+        col: u32,
+        piece: &GoPlayer,
+        removed_stones: &[(u32, u32)],
+    ) -> Result<u64, OutOfBoundsError> {
+        self.check_bounds(row, col)?;
+
+        let mut hash = self.hash ^ Self::zobrist_key(row, col, piece);
+        for (removed_row, removed_col) in removed_stones {
+            let removed_piece = match (*removed_row, *removed_col) == (row, col) {
+                true => Some(*piece),
+                false => self.get(*removed_row, *removed_col)?,
+            };
+            if let Some(removed_piece) = removed_piece {
+                hash ^= Self::zobrist_key(*removed_row, *removed_col, &removed_piece);
             }
         }
+
+        Ok(hash)
+    }
+
+    fn zobrist_key(row: u32, col: u32, piece: &GoPlayer) -> u64 {
+        let colour = match piece {
+            GoPlayer::BLACK => 0,
+            GoPlayer::WHITE => 1,
+        };
+
+        // SplitMix64 over the point and colour gives every (row, col, colour)
+        // a fixed pseudo-random key without a lookup table, so the keys don't
+        // depend on the board dimensions.
+        let mut z = ((row as u64) << 33 | (col as u64) << 1 | colour)
+            .wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
     }
 
+    fn stones_mut(&mut self, player: &GoPlayer) -> &mut Bitboard {
+        match player {
+            GoPlayer::BLACK => &mut self.black_stones,
+            GoPlayer::WHITE => &mut self.white_stones,
+        }
+    }
+}
+
+#[cfg(test)]
+impl GoBoard {
+    /// Builds a board from one string per row, `X` for Black's stones, `O`
+    /// for White's and anything else for an empty point.
+    pub(crate) fn from_rows(rows: &[&str]) -> GoBoard {
+        let mut board = GoBoard::with_dimensions(rows.len() as u32, rows[0].len() as u32).unwrap();
+        for (row, line) in rows.iter().enumerate() {
+            for (col, point) in line.chars().enumerate() {
+                let player = match point {
+                    'X' => GoPlayer::BLACK,
+                    'O' => GoPlayer::WHITE,
+                    _ => continue,
+                };
+                board.place(row as u32, col as u32, &player);
+            }
+        }
+
+        board
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
+    use crate::domain::util::group_liberties_aggregator::GroupLibertiesAggregator;
+
     use super::*;
 
     #[test]
     fn test_new_board() {
         let board = GoBoard::new(10);
-        assert_eq!(10, board.board_state.len());
-        assert_eq!(10, board.board_state[0].len());
+        assert_eq!(10, board.board_state().len());
+        assert_eq!(10, board.board_state()[0].len());
+    }
+
+    #[test]
+    fn test_board_with_dimensions() {
+        let board = GoBoard::with_dimensions(9, 13).unwrap();
+        assert_eq!(9, board.rows());
+        assert_eq!(13, board.cols());
+        assert_eq!(9, board.board_state().len());
+        assert_eq!(13, board.board_state()[0].len());
+
+        assert_eq!(Ok(()), board.check_bounds(8, 12));
+        assert!(board.check_bounds(12, 8).is_err());
+
+        assert_eq!(Ok(GoBoard::new(52)), GoBoard::with_dimensions(52, 52));
+        assert_eq!(Err(BoardSizeError { rows: 0, cols: 9 }), GoBoard::with_dimensions(0, 9));
+        assert_eq!(Err(BoardSizeError { rows: 9, cols: 0 }), GoBoard::with_dimensions(9, 0));
+        assert_eq!(Err(BoardSizeError { rows: 53, cols: 1 }), GoBoard::with_dimensions(53, 1));
+        assert_eq!(
+            "A 0x9 board is not supported, both sides must be between 1 and 52.",
+            BoardSizeError { rows: 0, cols: 9 }.to_string()
+        );
     }
 
     #[test]
@@ -62,28 +381,28 @@ mod tests {
         assert!(!board.place(0, 0, &GoPlayer::BLACK));
         assert!(!board.place(0, 0, &GoPlayer::WHITE));
 
-        assert_eq!(Some(GoPlayer::BLACK), board.board_state[0][0]);
+        assert_eq!(Some(GoPlayer::BLACK), board.board_state()[0][0]);
 
         assert!(board.place(5, 5, &GoPlayer::BLACK));
         assert!(board.place(9, 9, &GoPlayer::WHITE));
 
-        assert_eq!(Some(GoPlayer::BLACK), board.board_state[5][5]);
-        assert_eq!(Some(GoPlayer::WHITE), board.board_state[9][9]);
+        assert_eq!(Some(GoPlayer::BLACK), board.board_state()[5][5]);
+        assert_eq!(Some(GoPlayer::WHITE), board.board_state()[9][9]);
     }
 
     #[test]
     fn test_remove_pieces() {
         let mut board = GoBoard::new(10);
 
-        assert_eq!(None, board.board_state[0][0]);
+        assert_eq!(None, board.board_state()[0][0]);
         board.remove(0, 0);
-        assert_eq!(None, board.board_state[0][0]);
+        assert_eq!(None, board.board_state()[0][0]);
 
-        assert_eq!(None, board.board_state[5][5]);
+        assert_eq!(None, board.board_state()[5][5]);
         board.place(5, 5, &GoPlayer::BLACK);
-        assert_eq!(Some(GoPlayer::BLACK), board.board_state[5][5]);
+        assert_eq!(Some(GoPlayer::BLACK), board.board_state()[5][5]);
         board.remove(5, 5);
-        assert_eq!(None, board.board_state[5][5]);
+        assert_eq!(None, board.board_state()[5][5]);
     }
 
     #[test]
@@ -93,14 +412,286 @@ mod tests {
         assert!(board.place(0, 0, &GoPlayer::BLACK));
         assert!(board.place(0, 1, &GoPlayer::WHITE));
 
-        assert_eq!(Some(GoPlayer::BLACK), board.board_state[0][0]);
-        assert_eq!(Some(GoPlayer::WHITE), board.board_state[0][1]);
+        assert_eq!(Some(GoPlayer::BLACK), board.board_state()[0][0]);
+        assert_eq!(Some(GoPlayer::WHITE), board.board_state()[0][1]);
+
+        board.clear();
+
+        for row in 0..board.board_state().len() {
+            for col in 0..board.board_state()[0].len() {
+                assert_eq!(None, board.board_state()[row][col]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hash_updated_incrementally() {
+        let mut board = GoBoard::new(10);
+        assert_eq!(0, board.hash());
+
+        board.place(0, 0, &GoPlayer::BLACK);
+        let hash_after_black = board.hash();
+        assert_ne!(0, hash_after_black);
+
+        assert!(!board.place(0, 0, &GoPlayer::WHITE));
+        assert_eq!(hash_after_black, board.hash());
+
+        board.place(5, 5, &GoPlayer::WHITE);
+        assert_ne!(hash_after_black, board.hash());
+
+        board.remove(5, 5);
+        assert_eq!(hash_after_black, board.hash());
+
+        board.remove(5, 5);
+        assert_eq!(hash_after_black, board.hash());
+
+        board.clear();
+        assert_eq!(0, board.hash());
+    }
+
+    #[test]
+    fn test_hash_independent_of_move_order() {
+        let mut first_board = GoBoard::new(10);
+        first_board.place(0, 0, &GoPlayer::BLACK);
+        first_board.place(3, 4, &GoPlayer::WHITE);
+        first_board.place(4, 3, &GoPlayer::BLACK);
+
+        let mut second_board = GoBoard::new(10);
+        second_board.place(4, 3, &GoPlayer::BLACK);
+        second_board.place(0, 0, &GoPlayer::BLACK);
+        second_board.place(3, 4, &GoPlayer::WHITE);
+
+        assert_eq!(first_board.hash(), second_board.hash());
+    }
+
+    #[test]
+    fn test_hash_after_placing() {
+        let mut board = GoBoard::new(5);
+        board.place(0, 1, &GoPlayer::WHITE);
+        board.place(1, 1, &GoPlayer::BLACK);
+
+        let predicted_hash = board.hash_after_placing(0, 0, &GoPlayer::BLACK, &[(0, 1)]).unwrap();
+        let predicted_suicide_hash = board.hash_after_placing(0, 0, &GoPlayer::BLACK, &[(0, 0), (1, 1)]).unwrap();
+
+        let mut expected_board = board.clone();
+        expected_board.place(0, 0, &GoPlayer::BLACK);
+        expected_board.remove(0, 1);
+        assert_eq!(expected_board.hash(), predicted_hash);
+
+        let mut expected_board = board.clone();
+        expected_board.remove(1, 1);
+        assert_eq!(expected_board.hash(), predicted_suicide_hash);
+
+        assert_eq!(
+            Err(OutOfBoundsError { row: 5, col: 0 }),
+            board.hash_after_placing(5, 0, &GoPlayer::BLACK, &[])
+        );
+        assert_eq!(
+            Err(OutOfBoundsError { row: 0, col: 7 }),
+            board.hash_after_placing(0, 0, &GoPlayer::BLACK, &[(0, 7)])
+        );
+    }
+
+    #[test]
+    fn test_hash_distinguishes_colour_and_point() {
+        let mut black_board = GoBoard::new(10);
+        black_board.place(3, 4, &GoPlayer::BLACK);
+
+        let mut white_board = GoBoard::new(10);
+        white_board.place(3, 4, &GoPlayer::WHITE);
+
+        let mut transposed_board = GoBoard::new(10);
+        transposed_board.place(4, 3, &GoPlayer::BLACK);
+
+        assert_ne!(black_board.hash(), white_board.hash());
+        assert_ne!(black_board.hash(), transposed_board.hash());
+    }
+
+    #[test]
+    fn test_chain_stones_and_liberties() {
+        let mut board = GoBoard::with_dimensions(3, 5).unwrap();
+
+        //|X|X|O| | |
+        //| |X|O| |X|
+        //| | | | | |
+
+        board.place(0, 0, &GoPlayer::BLACK);
+        board.place(0, 1, &GoPlayer::BLACK);
+        board.place(1, 1, &GoPlayer::BLACK);
+        board.place(1, 4, &GoPlayer::BLACK);
+        board.place(0, 2, &GoPlayer::WHITE);
+        board.place(1, 2, &GoPlayer::WHITE);
+
+        let chain = board.chain_stones(1, 1);
+        assert_eq!(vec![(0, 0), (0, 1), (1, 1)], chain.points());
+        assert_eq!(vec![(1, 0), (2, 1)], board.chain_liberties(&chain).points());
+
+        let chain = board.chain_stones(0, 2);
+        assert_eq!(vec![(0, 2), (1, 2)], chain.points());
+        assert_eq!(vec![(0, 3), (1, 3), (2, 2)], board.chain_liberties(&chain).points());
+
+        assert!(board.chain_stones(2, 2).is_empty());
+        assert!(board.chain_stones(7, 7).is_empty());
+
+        board.remove(0, 1);
+        assert_eq!(vec![(0, 0), (1, 1), (1, 4)], board.stones(&GoPlayer::BLACK).points());
+        assert!(board.empty_points().contains(0, 1));
+        assert_eq!(vec![(0, 0)], board.chain_stones(0, 0).points());
+
+        assert_eq!(vec![(0, 2), (1, 2)], board.remove_chain(1, 2));
+        assert_eq!(vec![(0, 1), (1, 0), (1, 2), (2, 1)], board.chain_at(1, 1).unwrap().liberties.points());
+        assert!(board.remove_chain(1, 2).is_empty());
 
         board.clear();
+        assert!(board.stones(&GoPlayer::WHITE).is_empty());
+        assert_eq!(None, board.chain_at(0, 0));
+        assert_eq!(15, board.empty_points().count());
+    }
+
+    #[test]
+    fn test_point_queries() {
+        let mut board = GoBoard::with_dimensions(4, 5).unwrap();
+
+        //|O|X| | | |
+        //|O|X|X|O| |
+        //| |O|X|O| |
+        //| | |O| | |
+
+        let stones = [
+            (0, 1, GoPlayer::BLACK),
+            (1, 1, GoPlayer::BLACK),
+            (1, 2, GoPlayer::BLACK),
+            (2, 2, GoPlayer::BLACK),
+            (0, 0, GoPlayer::WHITE),
+            (1, 0, GoPlayer::WHITE),
+            (1, 3, GoPlayer::WHITE),
+            (2, 1, GoPlayer::WHITE),
+            (2, 3, GoPlayer::WHITE),
+            (3, 2, GoPlayer::WHITE),
+        ];
+        for (row, col, player) in stones {
+            board.place(row, col, &player);
+        }
+
+        assert_eq!(
+            Ok(Some(Group::from([(0, 1), (1, 1), (1, 2), (2, 2)]))),
+            board.group_at(2, 2)
+        );
+        assert_eq!(Ok(Some(Liberties::from([(0, 2)]))), board.liberties_at(0, 1));
+        assert_eq!(Ok(Some(Liberties::from([(2, 0)]))), board.liberties_at(0, 0));
+        assert_eq!(Ok(None), board.group_at(3, 4));
+        assert_eq!(Ok(None), board.liberties_at(3, 4));
+        assert_eq!(Err(OutOfBoundsError { row: 4, col: 0 }), board.group_at(4, 0));
+        assert_eq!(Err(OutOfBoundsError { row: 0, col: 5 }), board.liberties_at(0, 5));
+
+        assert_eq!(
+            vec![Group::from([(0, 1), (1, 1), (1, 2), (2, 2)])],
+            board.chains_in_atari(&GoPlayer::BLACK)
+        );
+        assert_eq!(
+            vec![Group::from([(0, 0), (1, 0)])],
+            board.chains_in_atari(&GoPlayer::WHITE)
+        );
+
+        assert_eq!(
+            Ok(vec![
+                Group::from([(0, 0), (1, 0)]),
+                Group::from([(1, 3), (2, 3)]),
+                Group::from([(2, 1)]),
+                Group::from([(3, 2)]),
+            ]),
+            board.adjacent_enemy_groups(1, 1)
+        );
+        assert_eq!(
+            Ok(vec![Group::from([(0, 1), (1, 1), (1, 2), (2, 2)])]),
+            board.adjacent_enemy_groups(3, 2)
+        );
+        assert_eq!(Ok(Vec::new()), board.adjacent_enemy_groups(0, 4));
+        assert_eq!(Err(OutOfBoundsError { row: 9, col: 9 }), board.adjacent_enemy_groups(9, 9));
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let mut board = GoBoard::new(10);
+
+        assert_eq!(Ok(()), board.check_bounds(9, 9));
+        assert_eq!(Err(OutOfBoundsError { row: 10, col: 0 }), board.check_bounds(10, 0));
+        assert_eq!(Err(OutOfBoundsError { row: 0, col: 10 }), board.get(0, 10));
+
+        assert!(!board.place(10, 10, &GoPlayer::BLACK));
+        board.remove(u32::MAX, 3);
+
+        assert_eq!(GoBoard::new(10), board);
+        assert_eq!(
+            "The point (10, 0) is outside the board.",
+            OutOfBoundsError { row: 10, col: 0 }.to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "A 0x0 board is not supported")]
+    fn test_new_rejects_empty_board() {
+        GoBoard::new(0);
+    }
+
+    proptest! {
+        #[test]
+        fn test_chains_match_aggregator(
+            rows in 1u32..12,
+            cols in 1u32..12,
+            actions in prop::collection::vec((0u32..12, 0u32..12, 0u8..4), 0..150)
+        ) {
+            let mut board = GoBoard::with_dimensions(rows, cols).unwrap();
+            for (row, col, action) in actions {
+                match action {
+                    0 => { board.place(row, col, &GoPlayer::BLACK); },
+                    1 => { board.place(row, col, &GoPlayer::WHITE); },
+                    2 => board.remove(row, col),
+                    _ => { board.remove_chain(row, col); }
+                }
+
+                let mut chain_count = 0;
+                for (player, groups) in GroupLibertiesAggregator::get_group_liberties(&board) {
+                    for (group, liberties) in groups {
+                        let (row, col) = *group.iter().next().unwrap();
+                        let chain = board.chain_at(row, col).unwrap();
+
+                        let mut expected_group = group.into_iter().collect::<Vec<_>>();
+                        expected_group.sort();
+                        let mut expected_liberties = liberties.into_iter().collect::<Vec<_>>();
+                        expected_liberties.sort();
+
+                        prop_assert_eq!(player, chain.player);
+                        prop_assert_eq!(expected_liberties.len() as u32, chain.liberty_count());
+                        prop_assert_eq!(expected_liberties, chain.liberties.points());
+                        prop_assert_eq!(&expected_group, &chain.stones.points());
+                        prop_assert_eq!(expected_group, board.chain_stones(row, col).points());
+                        chain_count += 1;
+                    }
+                }
+                prop_assert_eq!(chain_count, board.chains.chains().count());
+            }
+        }
+
+        #[test]
+        fn test_random_coordinates_never_panic(
+            size in 1u32..20,
+            points in prop::collection::vec(
+                (prop_oneof![0u32..25, any::<u32>()], prop_oneof![0u32..25, any::<u32>()], any::<bool>()),
+                0..50
+            )
+        ) {
+            let mut board = GoBoard::new(size);
+
+            for (row, col, is_black) in points {
+                let piece = if is_black { GoPlayer::BLACK } else { GoPlayer::WHITE };
+
+                let on_board = row < size && col < size;
+                prop_assert_eq!(on_board, board.check_bounds(row, col).is_ok());
 
-        for row in (0..board.board_state.len()) {
-            for col in (0..board.board_state[0].len()) {
-                assert_eq!(None, board.board_state[row][col]);
+                board.place(row, col, &piece);
+                board.get(row, col).ok();
+                board.remove(row, col);
             }
         }
     }
@@ -1,32 +1,1139 @@
+use super::bitboard::Bitboard;
+use super::game_config::GameConfig;
+use super::game_config::GameConfigError;
+use super::game_config::Ruleset;
+use super::game_status::DeadStoneMarking;
+use super::game_status::GameResult;
+use super::game_status::GameStatus;
+use super::go_board::BoardSizeError;
 use super::go_board::GoBoardState;
 use super::go_board::GoPlayer;
 use super::go_board::GoBoard;
-use super::util::group_liberties_aggregator::Group;
+use super::go_board::OutOfBoundsError;
+use super::handicap::Handicap;
+use super::handicap::HandicapError;
+use super::handicap::HandicapKomi;
+use super::handicap::HandicapPlacement;
+use super::handicap::MIN_HANDICAP;
+use super::scoring::ScoreComparison;
+use super::scoring::ScoreResult;
+use super::scoring::Scorer;
+use super::scoring::ScoringMethod;
+use super::setup::Setup;
 use super::util::group_liberties_aggregator::GroupLibertiesAggregator;
-use super::util::group_liberties_aggregator::Liberties;
 
-use std::sync::Mutex;
-use std::thread;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GoMove {
+    Place(u32, u32),
+    Pass
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KoRule {
+    Simple,
+    PositionalSuperko,
+    SituationalSuperko
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GameInfo {
+    pub black_name: Option<String>,
+    pub white_name: Option<String>,
+    pub rules: Option<String>,
+    pub result: Option<String>
+}
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Snapshot {
     pub state: GoBoardState,
-    pub chosen_move: (u32, u32)
+    pub hash: u64,
+    pub chosen_move: GoMove
+}
+
+/// Everything needed to take a move back: the stones it removed, the ko
+/// snapshot that it pushed out of `two_previous_states` and the status the
+/// game had before it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoveRecord {
+    pub player: GoPlayer,
+    pub chosen_move: GoMove,
+    pub captured_stones: Vec<(u32, u32)>,
+    pub suicided_stones: Vec<(u32, u32)>,
+    /// The prisoners a pass gave the opponent under the rules at the time.
+    pub pass_prisoners: u32,
+    pub dropped_snapshot: Option<Snapshot>,
+    pub new_position: bool,
+    pub status_before: GameStatus
+}
+
+/// Everything needed to take a setup back: what was on the points it
+/// changed and whose turn it was.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetupRecord {
+    /// How many moves had been made when the setup was applied.
+    pub move_number: usize,
+    pub setup: Setup,
+    pub replaced: Vec<((u32, u32), Option<GoPlayer>)>,
+    pub player_before: GoPlayer,
+    pub new_position: bool
+}
+
+/// Why the current player can't place a stone on a point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementError {
+    GameOver,
+    OutOfBounds,
+    Occupied { occupant: GoPlayer },
+    Ko,
+    Suicide
+}
+
+/// What placing a stone would do to the board: the stones it would take
+/// off, each list in row-major order, and the hash of the board after.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    pub captured_stones: Vec<(u32, u32)>,
+    pub suicided_stones: Vec<(u32, u32)>,
+    pub hash: u64
+}
+
+/// Where a move would lead, as worked out by `GoGame::preview_move`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MovePreview {
+    pub board_state: GoBoardState,
+    pub next_player: GoPlayer,
+    pub captured_stones: Vec<(u32, u32)>,
+    pub suicided_stones: Vec<(u32, u32)>,
+    pub game_over: bool
 }
 
 #[derive(Clone)]
 pub struct GoGame {
     pub current_player: GoPlayer,
     pub board: GoBoard,
-    pub two_previous_states: Vec<Option<Snapshot>>
+    pub two_previous_states: Vec<Option<Snapshot>>,
+    pub move_history: Vec<MoveRecord>,
+    pub undone_moves: Vec<MoveRecord>,
+    /// The setups applied to the board, in order. Moves and setups can be
+    /// interleaved, as they are in SGF records.
+    pub setup_history: Vec<SetupRecord>,
+    pub status: GameStatus,
+    pub suicide_allowed: bool,
+    pub ko_rule: KoRule,
+    pub position_history: HashSet<(u64, GoPlayer)>,
+    pub prisoners: HashMap<GoPlayer, u32>,
+    pub komi: f32,
+    pub scoring_method: ScoringMethod,
+    pub handicap_komi: HandicapKomi,
+    pub pass_gives_prisoner: bool,
+    pub dead_stone_marking: DeadStoneMarking,
+    /// How many moves had been made when play was last resumed from
+    /// scoring. Passes made before that don't count towards ending the
+    /// game again.
+    pub resumed_at: Option<usize>,
+    /// Black's handicap stones in row-major order, empty in an even game.
+    pub handicap_stones: Vec<(u32, u32)>,
+    pub game_info: GameInfo
 }
 
 impl GoGame {
+    /// Starts an even game on a square board by the default rules, which is
+    /// `with_config(GameConfig::default_for(size))`, panicking on the sizes
+    /// `with_dimensions` rejects.
     pub fn new(size: u32) -> Self {
-        Self { 
+        Self::with_config(GameConfig::default_for(size)).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Starts an even game by the default rules.
+    pub fn with_dimensions(rows: u32, cols: u32) -> Result<Self, BoardSizeError> {
+        Ok(Self::with_board(GoBoard::with_dimensions(rows, cols)?))
+    }
+
+    /// Sets up a game from its size, ruleset and handicap. The komi is the
+    /// ruleset's, or its handicap komi in a handicap game, unless the
+    /// config gives one.
+    pub fn with_config(config: GameConfig) -> Result<Self, GameConfigError> {
+        let mut game = Self::with_dimensions(config.rows, config.cols)?;
+        game.set_ruleset(&config.ruleset);
+
+        if let Some(placement) = config.handicap {
+            game.place_handicap(placement)?;
+        }
+        if let Some(komi) = config.komi {
+            game.komi = komi;
+        }
+
+        Ok(game)
+    }
+
+    fn with_board(board: GoBoard) -> Self {
+        let ruleset = Ruleset::default();
+        let mut game = Self {
             current_player: GoPlayer::BLACK,
-            board: GoBoard::new(size),
-            two_previous_states: vec![None, None]
+            board,
+            two_previous_states: vec![None, None],
+            move_history: Vec::new(),
+            undone_moves: Vec::new(),
+            setup_history: Vec::new(),
+            status: GameStatus::InProgress,
+            suicide_allowed: ruleset.suicide_allowed,
+            ko_rule: ruleset.ko_rule,
+            position_history: HashSet::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]),
+            komi: ruleset.komi,
+            scoring_method: ruleset.scoring_method,
+            handicap_komi: ruleset.handicap_komi,
+            pass_gives_prisoner: ruleset.pass_gives_prisoner,
+            dead_stone_marking: DeadStoneMarking::default(),
+            resumed_at: None,
+            handicap_stones: Vec::new(),
+            game_info: GameInfo::default()
+        };
+        game.record_position();
+
+        game
+    }
+
+    /// Plays by the ruleset from now on, taking its komi and recording its
+    /// name, if it has one, in the game info.
+    pub fn set_ruleset(&mut self, ruleset: &Ruleset) {
+        self.komi = ruleset.komi;
+        self.ko_rule = ruleset.ko_rule;
+        self.suicide_allowed = ruleset.suicide_allowed;
+        self.scoring_method = ruleset.scoring_method;
+        self.handicap_komi = ruleset.handicap_komi;
+        self.pass_gives_prisoner = ruleset.pass_gives_prisoner;
+        self.game_info.rules = (!ruleset.name.is_empty()).then(|| ruleset.name.clone());
+    }
+
+    /// Puts Black's handicap stones on the empty board before the first
+    /// move, hands the first move to White and sets the komi for a handicap
+    /// game by `handicap_komi`.
+    pub fn place_handicap(&mut self, placement: HandicapPlacement) -> Result<(), HandicapError> {
+        let empty_points = self.board.empty_points().count() as usize;
+        if !self.move_history.is_empty() || empty_points != (self.board.rows() * self.board.cols()) as usize {
+            return Err(HandicapError::GameStarted);
+        }
+
+        let points = match placement {
+            HandicapPlacement::Fixed { stones } => Handicap::fixed_points(self.board.rows(), self.board.cols(), stones)?,
+            HandicapPlacement::Free { mut points } => {
+                let stones = points.len() as u32;
+                if stones < MIN_HANDICAP {
+                    return Err(HandicapError::TooFewStones { stones });
+                }
+                if points.len() >= empty_points {
+                    return Err(HandicapError::TooManyStones { stones });
+                }
+                if let Some((row, col)) = points.iter().find(|(row, col)| self.board.check_bounds(*row, *col).is_err()) {
+                    return Err(HandicapError::OutOfBounds { row: *row, col: *col });
+                }
+
+                points.sort();
+                if let Some(pair) = points.windows(2).find(|pair| pair[0] == pair[1]) {
+                    return Err(HandicapError::DuplicatePoint { row: pair[0].0, col: pair[0].1 });
+                }
+
+                points
+            }
+        };
+
+        self.apply_setup(Setup {
+            black_stones: points.clone(),
+            player: Some(GoPlayer::WHITE),
+            ..Setup::default()
+        })
+        .unwrap();
+        self.komi = Handicap::komi(self.handicap_komi, points.len() as u32);
+        self.handicap_stones = points;
+
+        Ok(())
+    }
+
+    /// Changes the board outside of play, recording the setup in
+    /// `setup_history` so that `unapply_setup` can take it back. Nothing
+    /// changes if any of its points are off the board.
+    pub fn apply_setup(&mut self, setup: Setup) -> Result<(), OutOfBoundsError> {
+        let changes = setup.changes();
+        for ((row, col), _) in changes.iter() {
+            self.board.check_bounds(*row, *col)?;
+        }
+
+        let mut replaced = Vec::new();
+        for ((row, col), piece) in changes {
+            replaced.push(((row, col), self.board.get(row, col)?));
+            self.board.remove(row, col);
+            if let Some(piece) = piece {
+                self.board.place(row, col, &piece);
+            }
+        }
+
+        let player_before = self.current_player;
+        if let Some(player) = setup.player {
+            self.current_player = player;
+        }
+        let new_position = self.record_position();
+
+        self.setup_history.push(SetupRecord {
+            move_number: self.move_history.len(),
+            setup,
+            replaced,
+            player_before,
+            new_position
+        });
+
+        Ok(())
+    }
+
+    /// Takes back the last setup if no move has been made since, returning
+    /// it.
+    pub fn unapply_setup(&mut self) -> Option<Setup> {
+        if !self.setup_follows_last_move() {
+            return None;
+        }
+        let record = self.setup_history.pop().unwrap();
+
+        if record.new_position {
+            self.position_history.remove(&(self.board.hash(), self.current_player));
+        }
+
+        for ((row, col), piece) in record.replaced.into_iter().rev() {
+            self.board.remove(row, col);
+            if let Some(piece) = piece {
+                self.board.place(row, col, &piece);
+            }
+        }
+        self.current_player = record.player_before;
+
+        Some(record.setup)
+    }
+
+    /// Whether play has stopped, either to score the game or because it
+    /// is finished.
+    pub fn is_over(&self) -> bool {
+        self.status != GameStatus::InProgress
+    }
+
+    /// Goes back to playing from the scoring phase, dropping the marking.
+    /// The passes that ended play don't count towards ending it again.
+    /// Returns the `resumed_at` it replaces, which `unresume_play` needs.
+    pub fn resume_play(&mut self) -> Option<usize> {
+        self.status = GameStatus::InProgress;
+        self.dead_stone_marking = DeadStoneMarking::default();
+        self.resumed_at.replace(self.move_history.len())
+    }
+
+    /// Takes back `resume_play` before any move has been made since,
+    /// putting back the `resumed_at` it replaced. Scoring starts again from
+    /// an empty marking.
+    pub fn unresume_play(&mut self, resumed_at: Option<usize>) {
+        debug_assert_eq!(Some(self.move_history.len()), self.resumed_at);
+
+        self.status = GameStatus::Scoring;
+        self.resumed_at = resumed_at;
+    }
+
+    /// The result of the game once it is finished.
+    pub fn result(&self) -> Option<GameResult> {
+        match self.status {
+            GameStatus::Finished { result } => Some(result),
+            _ => None
         }
     }
-}
\ No newline at end of file
+
+    /// Remembers the current board together with the player to move, so
+    /// that superko can detect a position being repeated later on. Returns
+    /// whether the position is new.
+    pub fn record_position(&mut self) -> bool {
+        self.position_history.insert((self.board.hash(), self.current_player))
+    }
+
+    /// Works out what the current player placing a stone at (row, col)
+    /// would do without changing the game. The checks are the ones
+    /// `place_piece` makes, in the same order.
+    pub fn check_placement(&self, row: u32, col: u32) -> Result<Placement, PlacementError> {
+        if self.is_over() {
+            return Err(PlacementError::GameOver);
+        }
+
+        self.board.check_bounds(row, col).map_err(|_| PlacementError::OutOfBounds)?;
+
+        if self.ko_rule == KoRule::Simple && self.repeats_position_before_last(row, col) {
+            return Err(PlacementError::Ko);
+        }
+
+        if let Some(occupant) = self.board.board_state()[row as usize][col as usize] {
+            return Err(PlacementError::Occupied { occupant });
+        }
+
+        let player = self.current_player;
+        let (rows, cols) = (self.board.rows(), self.board.cols());
+
+        // A neighbouring chain's last liberty can only be this point, so the
+        // tracked liberty counts are enough to tell what the stone takes.
+        let mut captured = Bitboard::new(rows, cols);
+        let mut own_chain = Bitboard::from_points(rows, cols, &[(row, col)]);
+        let mut has_liberties = false;
+        for (adjacent_row, adjacent_col) in GroupLibertiesAggregator::get_adjacent_points(&self.board, row, col) {
+            match self.board.chain_at(adjacent_row, adjacent_col) {
+                None => has_liberties = true,
+                Some(chain) if chain.player == player => {
+                    own_chain = &own_chain | &chain.stones;
+                    has_liberties |= chain.liberty_count() > 1;
+                }
+                Some(chain) => {
+                    if chain.liberty_count() == 1 {
+                        captured = &captured | &chain.stones;
+                    }
+                }
+            }
+        }
+
+        let captured_stones = captured.points();
+        let mut suicided_stones = Vec::new();
+        if !has_liberties && captured_stones.is_empty() {
+            if !self.suicide_allowed {
+                return Err(PlacementError::Suicide);
+            }
+            suicided_stones = own_chain.points();
+        }
+
+        let removed_stones = [captured_stones.as_slice(), suicided_stones.as_slice()].concat();
+        let hash = self.board
+            .hash_after_placing(row, col, &player, &removed_stones)
+            .map_err(|_| PlacementError::OutOfBounds)?;
+
+        if self.repeats_earlier_position(hash) {
+            return Err(PlacementError::Ko);
+        }
+
+        Ok(Placement {
+            captured_stones,
+            suicided_stones,
+            hash
+        })
+    }
+
+    pub fn is_legal(&self, row: u32, col: u32) -> bool {
+        self.check_placement(row, col).is_ok()
+    }
+
+    /// Every move the current player can make, passing included. Nothing is
+    /// legal once the game is over.
+    pub fn legal_moves(&self) -> HashSet<GoMove> {
+        if self.is_over() {
+            return HashSet::new();
+        }
+
+        let mut legal_moves = HashSet::from([GoMove::Pass]);
+        for row in 0..self.board.rows() {
+            for col in 0..self.board.cols() {
+                if self.is_legal(row, col) {
+                    legal_moves.insert(GoMove::Place(row, col));
+                }
+            }
+        }
+
+        legal_moves
+    }
+
+    /// Shows what the current player making `chosen_move` would lead to,
+    /// or why the move isn't allowed, without changing the game.
+    pub fn preview_move(&self, chosen_move: GoMove) -> Result<MovePreview, PlacementError> {
+        let next_player = match self.current_player {
+            GoPlayer::BLACK => GoPlayer::WHITE,
+            GoPlayer::WHITE => GoPlayer::BLACK
+        };
+
+        match chosen_move {
+            GoMove::Place(row, col) => {
+                let placement = self.check_placement(row, col)?;
+
+                let mut board_state = self.board.board_state().clone();
+                board_state[row as usize][col as usize] = Some(self.current_player);
+                for (removed_row, removed_col) in placement.captured_stones.iter().chain(placement.suicided_stones.iter()) {
+                    board_state[*removed_row as usize][*removed_col as usize] = None;
+                }
+
+                Ok(MovePreview {
+                    board_state,
+                    next_player,
+                    captured_stones: placement.captured_stones,
+                    suicided_stones: placement.suicided_stones,
+                    game_over: false
+                })
+            }
+            GoMove::Pass => {
+                if self.is_over() {
+                    return Err(PlacementError::GameOver);
+                }
+
+                Ok(MovePreview {
+                    board_state: self.board.board_state().clone(),
+                    next_player,
+                    captured_stones: Vec::new(),
+                    suicided_stones: Vec::new(),
+                    game_over: self.opponent_passed_last()
+                })
+            }
+        }
+    }
+
+    /// Makes a move for the current player, recording it in `move_history`
+    /// so that `unapply` (or `undo_move`) can take it back. This is what
+    /// `place_piece` and `pass_turn` build on, without their outputs and
+    /// without clearing the redo stack, so search can call `apply` and
+    /// `unapply` in pairs cheaply.
+    pub fn apply(&mut self, chosen_move: GoMove) -> Result<(), PlacementError> {
+        let player = self.current_player;
+        let opponent = match player {
+            GoPlayer::BLACK => GoPlayer::WHITE,
+            GoPlayer::WHITE => GoPlayer::BLACK
+        };
+
+        let (captured_stones, suicided_stones) = match chosen_move {
+            GoMove::Place(row, col) => {
+                let placement = self.check_placement(row, col)?;
+                (placement.captured_stones, placement.suicided_stones)
+            }
+            GoMove::Pass => {
+                if self.is_over() {
+                    return Err(PlacementError::GameOver);
+                }
+                (Vec::new(), Vec::new())
+            }
+        };
+
+        let state_before_move = self.board.board_state().clone();
+        let hash_before_move = self.board.hash();
+        let opponent_passed = self.opponent_passed_last();
+        let status_before = self.status;
+
+        if let GoMove::Place(row, col) = chosen_move {
+            self.board.place(row, col, &player);
+            for (captured_row, captured_col) in captured_stones.iter() {
+                self.board.remove_chain(*captured_row, *captured_col);
+            }
+            if !suicided_stones.is_empty() {
+                self.board.remove_chain(row, col);
+            }
+        }
+
+        let pass_prisoners = (chosen_move == GoMove::Pass && self.pass_gives_prisoner) as u32;
+        *self.prisoners.get_mut(&player).unwrap() += captured_stones.len() as u32;
+        *self.prisoners.get_mut(&opponent).unwrap() += suicided_stones.len() as u32 + pass_prisoners;
+
+        self.current_player = opponent;
+
+        let dropped_snapshot = self.two_previous_states[1].take();
+        self.two_previous_states.rotate_right(1);
+        self.two_previous_states[0] = Some(Snapshot {
+            state: state_before_move,
+            hash: hash_before_move,
+            chosen_move
+        });
+        let new_position = self.record_position();
+
+        self.move_history.push(MoveRecord {
+            player,
+            chosen_move,
+            captured_stones,
+            suicided_stones,
+            pass_prisoners,
+            dropped_snapshot,
+            new_position,
+            status_before
+        });
+
+        if chosen_move == GoMove::Pass && opponent_passed {
+            self.status = GameStatus::Scoring;
+        }
+
+        Ok(())
+    }
+
+    /// Takes back the last move in `move_history` and returns its record,
+    /// or `None` if no move has been made or a setup has been applied since
+    /// the move. Such a setup has to be taken back with `unapply_setup`
+    /// first.
+    pub fn unapply(&mut self) -> Option<MoveRecord> {
+        if self.setup_follows_last_move() {
+            return None;
+        }
+        let record = self.move_history.pop()?;
+
+        let opponent = match record.player {
+            GoPlayer::BLACK => GoPlayer::WHITE,
+            GoPlayer::WHITE => GoPlayer::BLACK
+        };
+
+        if record.new_position {
+            self.position_history.remove(&(self.board.hash(), self.current_player));
+        }
+
+        for (row, col) in record.suicided_stones.iter() {
+            self.board.place(*row, *col, &record.player);
+        }
+        if let GoMove::Place(row, col) = record.chosen_move {
+            self.board.remove(row, col);
+        }
+        for (row, col) in record.captured_stones.iter() {
+            self.board.place(*row, *col, &opponent);
+        }
+
+        *self.prisoners.get_mut(&record.player).unwrap() -= record.captured_stones.len() as u32;
+        *self.prisoners.get_mut(&opponent).unwrap() -= record.suicided_stones.len() as u32 + record.pass_prisoners;
+
+        self.two_previous_states.rotate_left(1);
+        self.two_previous_states[1] = record.dropped_snapshot.clone();
+
+        // Any marking belongs to the scoring phase (or its result) being
+        // left.
+        if self.status != record.status_before {
+            self.dead_stone_marking = DeadStoneMarking::default();
+        }
+        self.current_player = record.player;
+        self.status = record.status_before;
+        if self.resumed_at.is_some_and(|resumed_at| self.move_history.len() < resumed_at) {
+            self.resumed_at = None;
+        }
+
+        Some(record)
+    }
+
+    /// Whether the last thing done to the board was a setup rather than a
+    /// move.
+    pub fn setup_follows_last_move(&self) -> bool {
+        matches!(self.setup_history.last(), Some(record) if record.move_number == self.move_history.len())
+    }
+
+    fn opponent_passed_last(&self) -> bool {
+        if self.resumed_at == Some(self.move_history.len()) {
+            return false;
+        }
+
+        matches!(self.move_history.last(), Some(record) if record.chosen_move == GoMove::Pass)
+    }
+
+    /// The original simple ko check: playing the same point again while the
+    /// board is back to how it was before the previous move. The point and
+    /// the board hashes are checked before the boards are compared, which
+    /// keeps `legal_moves` cheap.
+    fn repeats_position_before_last(&self, row: u32, col: u32) -> bool {
+        match &self.two_previous_states[1] {
+            Some(snapshot) => GoMove::Place(row, col) == snapshot.chosen_move
+                && self.board.hash() == snapshot.hash
+                && *self.board.board_state() == snapshot.state,
+            None => false
+        }
+    }
+
+    fn repeats_earlier_position(&self, hash: u64) -> bool {
+        let next_player = match self.current_player {
+            GoPlayer::BLACK => GoPlayer::WHITE,
+            GoPlayer::WHITE => GoPlayer::BLACK
+        };
+
+        match self.ko_rule {
+            KoRule::Simple => false,
+            KoRule::PositionalSuperko => [GoPlayer::BLACK, GoPlayer::WHITE]
+                .iter()
+                .any(|player| self.position_history.contains(&(hash, *player))),
+            KoRule::SituationalSuperko => self.position_history.contains(&(hash, next_player))
+        }
+    }
+
+    pub fn moves(&self) -> Vec<(GoPlayer, GoMove)> {
+        self.move_history
+            .iter()
+            .map(|record| (record.player, record.chosen_move))
+            .collect()
+    }
+
+    pub fn score(
+        &self,
+        scoring_method: ScoringMethod,
+        dead_stones: &[(u32, u32)],
+        komi: f32
+    ) -> Result<ScoreResult, OutOfBoundsError> {
+        match scoring_method {
+            ScoringMethod::Area => Scorer::area_score(&self.board, dead_stones, komi),
+            ScoringMethod::Territory => Scorer::territory_score(&self.board, dead_stones, &self.prisoners, komi)
+        }
+    }
+
+    /// Scores the game under both area and territory rules so that any
+    /// disagreement between the two rulesets can be reported.
+    pub fn compare_scoring(
+        &self,
+        dead_stones: &[(u32, u32)],
+        komi: f32
+    ) -> Result<ScoreComparison, OutOfBoundsError> {
+        Ok(ScoreComparison::new(
+            self.score(ScoringMethod::Area, dead_stones, komi)?,
+            self.score(ScoringMethod::Territory, dead_stones, komi)?
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::use_cases::pass_turn::pass_turn_input::PassTurnInput;
+    use crate::use_cases::pass_turn::pass_turn_requester::PassTurnRequester;
+    use crate::use_cases::place_piece::place_piece_input::PlacePieceInput;
+    use crate::use_cases::place_piece::place_piece_requester::PlacePieceRequester;
+
+    use super::*;
+
+    #[test]
+    fn test_legal_moves_empty_board() {
+        let game = GoGame::with_dimensions(2, 3).unwrap();
+
+        let mut expected_moves = HashSet::from([GoMove::Pass]);
+        for row in 0..2 {
+            for col in 0..3 {
+                expected_moves.insert(GoMove::Place(row, col));
+            }
+        }
+
+        assert_eq!(expected_moves, game.legal_moves());
+        assert!(game.is_legal(1, 2));
+        assert!(!game.is_legal(2, 1));
+    }
+
+    #[test]
+    fn test_legal_moves_skip_occupied_suicide_and_ko() {
+        let mut game = GoGame::new(4);
+        game.ko_rule = KoRule::PositionalSuperko;
+
+        //| |X|O| |
+        //|X|O| |O|
+        //| |X|O| |
+        //| | | | |
+
+        let moves = [
+            (0, 1, GoPlayer::BLACK),
+            (0, 2, GoPlayer::WHITE),
+            (1, 0, GoPlayer::BLACK),
+            (1, 3, GoPlayer::WHITE),
+            (2, 1, GoPlayer::BLACK),
+            (2, 2, GoPlayer::WHITE),
+        ];
+        for (row, col, player) in moves {
+            assert!(game.place_piece(PlacePieceInput { row, col, player }).success);
+        }
+
+        //White can't fill the corner, it has no liberties and takes nothing
+        game.pass_turn(PassTurnInput { player: GoPlayer::BLACK });
+        assert_eq!(Err(PlacementError::Suicide), game.check_placement(0, 0));
+        assert!(game.place_piece(PlacePieceInput { row: 1, col: 1, player: GoPlayer::WHITE }).success);
+
+        //Black takes the white stone, White can't take back straight away
+        let placement = game.check_placement(1, 2).unwrap();
+        assert_eq!(vec![(1, 1)], placement.captured_stones);
+        assert!(game.place_piece(PlacePieceInput { row: 1, col: 2, player: GoPlayer::BLACK }).success);
+        assert_eq!(Err(PlacementError::Ko), game.check_placement(1, 1));
+
+        let legal_moves = game.legal_moves();
+        assert!(!legal_moves.contains(&GoMove::Place(0, 0)));
+        assert!(!legal_moves.contains(&GoMove::Place(1, 1)));
+        assert!(!legal_moves.contains(&GoMove::Place(0, 1)));
+        assert!(!legal_moves.contains(&GoMove::Place(1, 2)));
+        assert!(legal_moves.contains(&GoMove::Place(3, 3)));
+        assert!(legal_moves.contains(&GoMove::Pass));
+        assert_eq!(
+            Err(PlacementError::Occupied { occupant: GoPlayer::BLACK }),
+            game.check_placement(0, 1)
+        );
+        assert_eq!(Err(PlacementError::OutOfBounds), game.check_placement(4, 0));
+    }
+
+    #[test]
+    fn test_no_legal_moves_after_game_over() {
+        let mut game = GoGame::new(9);
+        game.pass_turn(PassTurnInput { player: GoPlayer::BLACK });
+        game.pass_turn(PassTurnInput { player: GoPlayer::WHITE });
+
+        assert!(game.legal_moves().is_empty());
+        assert_eq!(Err(PlacementError::GameOver), game.check_placement(4, 4));
+    }
+
+    #[test]
+    fn test_fixed_handicap() {
+        let mut game = GoGame::new(9);
+        game.handicap_komi = HandicapKomi::PerStone;
+
+        game.place_handicap(HandicapPlacement::Fixed { stones: 2 }).unwrap();
+
+        //| | | | | | | | | |
+        //| | | | | | | | | |
+        //| | | | | | |X| | |
+        //| | | | | | | | | |
+        //| | | | | | | | | |
+        //| | | | | | | | | |
+        //| | |X| | | | | | |
+        //| | | | | | | | | |
+        //| | | | | | | | | |
+
+        assert_eq!(vec![(2, 6), (6, 2)], game.board.stones(&GoPlayer::BLACK).points());
+        assert_eq!(vec![(2, 6), (6, 2)], game.handicap_stones);
+        assert_eq!(GoPlayer::WHITE, game.current_player);
+        assert_eq!(2.5, game.komi);
+        assert!(game.move_history.is_empty());
+
+        let output = game.place_piece(PlacePieceInput { row: 4, col: 4, player: GoPlayer::WHITE });
+        assert!(output.success);
+        assert_eq!(Err(HandicapError::GameStarted), game.place_handicap(HandicapPlacement::Fixed { stones: 2 }));
+    }
+
+    #[test]
+    fn test_handicap_keeps_start_position() {
+        let mut game = GoGame::new(9);
+        let new_game = game.clone();
+
+        game.place_handicap(HandicapPlacement::Fixed { stones: 4 }).unwrap();
+
+        assert!(game.position_history.contains(&(new_game.board.hash(), GoPlayer::BLACK)));
+        assert!(game.position_history.contains(&(game.board.hash(), GoPlayer::WHITE)));
+        assert_eq!(2, game.position_history.len());
+
+        assert!(game.unapply_setup().is_some());
+        assert_eq!(new_game.position_history, game.position_history);
+    }
+
+    #[test]
+    fn test_free_handicap() {
+        let mut game = GoGame::with_dimensions(5, 7).unwrap();
+
+        game.place_handicap(HandicapPlacement::Free { points: vec![(4, 6), (0, 0), (2, 3)] }).unwrap();
+
+        assert_eq!(vec![(0, 0), (2, 3), (4, 6)], game.handicap_stones);
+        assert_eq!(vec![(0, 0), (2, 3), (4, 6)], game.board.stones(&GoPlayer::BLACK).points());
+        assert_eq!(GoPlayer::WHITE, game.current_player);
+        assert_eq!(0.5, game.komi);
+
+        assert_eq!(
+            Err(HandicapError::GameStarted),
+            game.place_handicap(HandicapPlacement::Free { points: vec![(1, 1), (3, 3)] })
+        );
+    }
+
+    #[test]
+    fn test_setup_and_unapply_setup() {
+        let mut game = GoGame::new(5);
+        game.apply(GoMove::Place(0, 0)).unwrap();
+
+        //|O| |X| | |
+        game.apply_setup(Setup {
+            black_stones: vec![(0, 2)],
+            white_stones: vec![(0, 0)],
+            empty_points: vec![(0, 0)],
+            player: Some(GoPlayer::BLACK)
+        })
+        .unwrap();
+
+        assert_eq!(Some(GoPlayer::WHITE), game.board.board_state()[0][0]);
+        assert_eq!(Some(GoPlayer::BLACK), game.board.board_state()[0][2]);
+        assert_eq!(GoPlayer::BLACK, game.current_player);
+        assert_eq!(1, game.setup_history[0].move_number);
+
+        assert_eq!(
+            Err(OutOfBoundsError { row: 5, col: 0 }),
+            game.apply_setup(Setup { black_stones: vec![(1, 1), (5, 0)], ..Setup::default() })
+        );
+        assert_eq!(None, game.board.board_state()[1][1]);
+
+        let expected_board = game.board.clone();
+        game.apply(GoMove::Place(3, 3)).unwrap();
+        game.apply_setup(Setup { white_stones: vec![(4, 4)], ..Setup::default() }).unwrap();
+
+        //The setup made after the move has to be taken back first
+        assert!(game.setup_follows_last_move());
+        assert_eq!(None, game.unapply());
+        assert_eq!(2, game.setup_history.len());
+        assert!(game.unapply_setup().is_some());
+        assert!(game.unapply().is_some());
+        assert_eq!(expected_board, game.board);
+        assert_eq!(1, game.setup_history.len());
+
+        assert!(game.unapply_setup().is_some());
+        assert_eq!(GoPlayer::WHITE, game.current_player);
+        assert_eq!(Some(GoPlayer::BLACK), game.board.board_state()[0][0]);
+        assert_eq!(None, game.board.board_state()[0][2]);
+        assert_eq!(None, game.unapply_setup());
+    }
+
+    #[test]
+    fn test_invalid_free_handicap() {
+        let mut game = GoGame::new(3);
+
+        for (points, error) in [
+            (vec![(1, 1)], HandicapError::TooFewStones { stones: 1 }),
+            (vec![(1, 1), (3, 0)], HandicapError::OutOfBounds { row: 3, col: 0 }),
+            (vec![(1, 1), (0, 2), (1, 1)], HandicapError::DuplicatePoint { row: 1, col: 1 }),
+            (vec![(0, 0); 9], HandicapError::TooManyStones { stones: 9 }),
+            (vec![], HandicapError::TooFewStones { stones: 0 })
+        ] {
+            assert_eq!(Err(error), game.place_handicap(HandicapPlacement::Free { points }));
+        }
+
+        assert_eq!(9, game.board.empty_points().count());
+        assert_eq!(GoPlayer::BLACK, game.current_player);
+        assert_eq!(
+            Err(HandicapError::UnsupportedFixedHandicap { rows: 3, cols: 3, stones: 2 }),
+            game.place_handicap(HandicapPlacement::Fixed { stones: 2 })
+        );
+    }
+
+    #[test]
+    fn test_game_from_config() {
+        let mut config = GameConfig::new(19, Ruleset::aga());
+        config.handicap = Some(HandicapPlacement::Fixed { stones: 4 });
+
+        let game = GoGame::with_config(config.clone()).unwrap();
+
+        assert_eq!(KoRule::SituationalSuperko, game.ko_rule);
+        assert!(!game.suicide_allowed);
+        assert_eq!(ScoringMethod::Area, game.scoring_method);
+        assert!(game.pass_gives_prisoner);
+        assert_eq!(3.5, game.komi);
+        assert_eq!(4, game.handicap_stones.len());
+        assert_eq!(GoPlayer::WHITE, game.current_player);
+        assert_eq!(Some(String::from("AGA")), game.game_info.rules);
+
+        config.komi = Some(0.0);
+        assert_eq!(0.0, GoGame::with_config(config).unwrap().komi);
+
+        let game = GoGame::with_config(GameConfig::new(9, Ruleset::new_zealand())).unwrap();
+        assert_eq!(7.0, game.komi);
+        assert!(game.suicide_allowed);
+        assert!(game.handicap_stones.is_empty());
+        assert_eq!(GoPlayer::BLACK, game.current_player);
+    }
+
+    #[test]
+    fn test_new_game_has_default_rules() {
+        let game = GoGame::new(9);
+        let ruleset = Ruleset::default();
+
+        assert_eq!(ruleset.komi, game.komi);
+        assert_eq!(ruleset.ko_rule, game.ko_rule);
+        assert_eq!(ruleset.suicide_allowed, game.suicide_allowed);
+        assert_eq!(ruleset.scoring_method, game.scoring_method);
+        assert_eq!(ruleset.handicap_komi, game.handicap_komi);
+        assert_eq!(ruleset.pass_gives_prisoner, game.pass_gives_prisoner);
+        assert_eq!(None, game.game_info.rules);
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert_eq!(
+            Err(GameConfigError::BoardSize(BoardSizeError { rows: 0, cols: 0 })),
+            GoGame::with_config(GameConfig::new(0, Ruleset::chinese())).map(|_| ())
+        );
+
+        let mut config = GameConfig::new(7, Ruleset::chinese());
+        config.handicap = Some(HandicapPlacement::Fixed { stones: 2 });
+        assert_eq!(
+            Err(GameConfigError::Handicap(HandicapError::UnsupportedFixedHandicap { rows: 7, cols: 7, stones: 2 })),
+            GoGame::with_config(config).map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_pass_gives_prisoner() {
+        let mut game = GoGame::with_config(GameConfig::new(9, Ruleset::aga())).unwrap();
+
+        game.apply(GoMove::Pass).unwrap();
+        game.apply(GoMove::Pass).unwrap();
+        assert_eq!(1, game.prisoners[&GoPlayer::BLACK]);
+        assert_eq!(1, game.prisoners[&GoPlayer::WHITE]);
+
+        game.unapply();
+        assert_eq!(0, game.prisoners[&GoPlayer::BLACK]);
+        assert_eq!(1, game.prisoners[&GoPlayer::WHITE]);
+
+        //Changing the rules between a pass and its undo takes back only what
+        //the pass gave
+        game.set_ruleset(&Ruleset::japanese());
+        game.unapply();
+        assert_eq!(0, game.prisoners[&GoPlayer::WHITE]);
+
+        game.apply(GoMove::Pass).unwrap();
+        game.set_ruleset(&Ruleset::aga());
+        game.unapply();
+        assert_eq!(0, game.prisoners[&GoPlayer::WHITE]);
+
+        let mut game = GoGame::with_config(GameConfig::new(9, Ruleset::japanese())).unwrap();
+        game.apply(GoMove::Pass).unwrap();
+        assert_eq!(0, game.prisoners[&GoPlayer::WHITE]);
+    }
+
+    #[test]
+    fn test_preview_capture() {
+        let mut game = GoGame::new(3);
+
+        //| |X| |
+        //|X|O| |
+        //| |X| |
+
+        game.board.place(0, 1, &GoPlayer::BLACK);
+        game.board.place(1, 0, &GoPlayer::BLACK);
+        game.board.place(2, 1, &GoPlayer::BLACK);
+        game.board.place(1, 1, &GoPlayer::WHITE);
+
+        let preview = game.preview_move(GoMove::Place(1, 2)).unwrap();
+
+        let mut expected_state = game.board.board_state().clone();
+        expected_state[1][1] = None;
+        expected_state[1][2] = Some(GoPlayer::BLACK);
+
+        assert_eq!(expected_state, preview.board_state);
+        assert_eq!(GoPlayer::WHITE, preview.next_player);
+        assert_eq!(vec![(1, 1)], preview.captured_stones);
+        assert!(preview.suicided_stones.is_empty());
+        assert!(!preview.game_over);
+
+        assert_eq!(Some(GoPlayer::WHITE), game.board.board_state()[1][1]);
+        assert_eq!(Err(PlacementError::Occupied { occupant: GoPlayer::BLACK }), game.preview_move(GoMove::Place(0, 1)));
+    }
+
+    #[test]
+    fn test_preview_second_pass_ends_game() {
+        let mut game = GoGame::new(9);
+
+        assert!(!game.preview_move(GoMove::Pass).unwrap().game_over);
+
+        game.apply(GoMove::Pass).unwrap();
+        let preview = game.preview_move(GoMove::Pass).unwrap();
+        assert!(preview.game_over);
+        assert_eq!(GoPlayer::BLACK, preview.next_player);
+        assert!(!game.is_over());
+
+        game.apply(GoMove::Pass).unwrap();
+        assert_eq!(GameStatus::Scoring, game.status);
+        assert_eq!(Err(PlacementError::GameOver), game.preview_move(GoMove::Pass));
+        assert_eq!(Err(PlacementError::GameOver), game.apply(GoMove::Pass));
+
+        assert!(game.unapply().is_some());
+        assert!(!game.is_over());
+        assert!(game.unapply().is_some());
+        assert!(game.unapply().is_none());
+    }
+
+    proptest! {
+        #[test]
+        fn test_legal_moves_match_place_piece(
+            size in 2u32..6,
+            suicide_allowed in any::<bool>(),
+            ko_rule in prop_oneof![
+                Just(KoRule::Simple),
+                Just(KoRule::PositionalSuperko),
+                Just(KoRule::SituationalSuperko)
+            ],
+            inputs in prop::collection::vec((0u32..6, 0u32..6), 0..60)
+        ) {
+            let mut game = GoGame::new(size);
+            game.suicide_allowed = suicide_allowed;
+            game.ko_rule = ko_rule;
+
+            for (row, col) in inputs {
+                let legal_moves = game.legal_moves();
+                let hash_before = game.board.hash();
+
+                for check_row in 0..size {
+                    for check_col in 0..size {
+                        let mut copy = game.clone();
+                        let player = copy.current_player;
+                        let output = copy.place_piece(PlacePieceInput { row: check_row, col: check_col, player });
+
+                        prop_assert_eq!(output.success, legal_moves.contains(&GoMove::Place(check_row, check_col)));
+                        if let Ok(placement) = game.check_placement(check_row, check_col) {
+                            prop_assert_eq!(copy.board.hash(), placement.hash);
+                        }
+                    }
+                }
+                prop_assert_eq!(hash_before, game.board.hash());
+
+                let player = game.current_player;
+                game.place_piece(PlacePieceInput { row, col, player });
+            }
+        }
+
+        #[test]
+        fn test_apply_matches_preview_and_unapply_restores(
+            size in 2u32..6,
+            suicide_allowed in any::<bool>(),
+            inputs in prop::collection::vec(prop_oneof![
+                1 => Just(GoMove::Pass),
+                9 => (0u32..6, 0u32..6).prop_map(|(row, col)| GoMove::Place(row, col))
+            ], 0..60)
+        ) {
+            let mut game = GoGame::new(size);
+            game.suicide_allowed = suicide_allowed;
+            game.ko_rule = KoRule::PositionalSuperko;
+
+            let mut played = vec![game.clone()];
+            for chosen_move in inputs {
+                let preview = game.preview_move(chosen_move);
+                let applied = game.apply(chosen_move);
+                prop_assert_eq!(preview.is_ok(), applied.is_ok());
+
+                if let Ok(preview) = preview {
+                    prop_assert_eq!(&preview.board_state, game.board.board_state());
+                    prop_assert_eq!(preview.next_player, game.current_player);
+                    prop_assert_eq!(preview.game_over, game.is_over());
+
+                    let record = game.move_history.last().unwrap();
+                    prop_assert_eq!(&preview.captured_stones, &record.captured_stones);
+                    prop_assert_eq!(&preview.suicided_stones, &record.suicided_stones);
+
+                    played.push(game.clone());
+                }
+            }
+
+            played.pop();
+            while let Some(expected) = played.pop() {
+                game.unapply().unwrap();
+
+                prop_assert!(expected.board == game.board);
+                prop_assert_eq!(expected.board.hash(), game.board.hash());
+                prop_assert_eq!(&expected.prisoners, &game.prisoners);
+                prop_assert_eq!(expected.current_player, game.current_player);
+                prop_assert_eq!(&expected.two_previous_states, &game.two_previous_states);
+                prop_assert_eq!(&expected.position_history, &game.position_history);
+                prop_assert_eq!(&expected.move_history, &game.move_history);
+            }
+            prop_assert!(game.unapply().is_none());
+        }
+    }
+
+    #[test]
+    fn test_compare_scoring() {
+        let mut game = GoGame::new(4);
+
+        //| |X|O| |
+        //| |X|O|X|
+        //| |X|O| |
+        //| |X|O| |
+
+        for row in 0..4 {
+            game.board.place(row, 1, &GoPlayer::BLACK);
+            game.board.place(row, 2, &GoPlayer::WHITE);
+        }
+        game.board.place(1, 3, &GoPlayer::BLACK);
+        game.prisoners.insert(GoPlayer::BLACK, 2);
+
+        let comparison = game.compare_scoring(&[(1, 3)], 0.5).unwrap();
+
+        assert_eq!(ScoreResult::new(8.0, 8.5), comparison.area);
+        assert_eq!(ScoreResult::new(6.0, 5.5), comparison.territory);
+        assert_eq!(-1.0, comparison.margin_difference);
+        assert!(!comparison.same_winner());
+    }
+}
@@ -0,0 +1,163 @@
+use std::error::Error;
+use std::fmt;
+
+pub const MIN_HANDICAP: u32 = 2;
+pub const MAX_FIXED_HANDICAP: u32 = 9;
+
+/// Where Black's handicap stones go: on the traditional star points, or on
+/// points Black picks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandicapPlacement {
+    Fixed { stones: u32 },
+    Free { points: Vec<(u32, u32)> }
+}
+
+/// How much komi White gets in a handicap game. Every rule keeps the half
+/// point that settles ties; under area scoring each handicap stone also
+/// counts as a point of Black's area, which some rules give back to White.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandicapKomi {
+    HalfPoint,
+    PerStone,
+    PerStoneAfterFirst
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandicapError {
+    GameStarted,
+    TooFewStones { stones: u32 },
+    UnsupportedFixedHandicap { rows: u32, cols: u32, stones: u32 },
+    TooManyStones { stones: u32 },
+    OutOfBounds { row: u32, col: u32 },
+    DuplicatePoint { row: u32, col: u32 }
+}
+
+impl fmt::Display for HandicapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandicapError::GameStarted =>
+                write!(f, "Handicap stones can only be placed before the game starts."),
+            HandicapError::TooFewStones { stones } =>
+                write!(f, "A handicap needs at least {} stones, not {}.", MIN_HANDICAP, stones),
+            HandicapError::UnsupportedFixedHandicap { rows, cols, stones } =>
+                write!(f, "There is no fixed placement for {} handicap stones on a {}x{} board.", stones, rows, cols),
+            HandicapError::TooManyStones { stones } =>
+                write!(f, "{} handicap stones would fill the board.", stones),
+            HandicapError::OutOfBounds { row, col } =>
+                write!(f, "The handicap stone at ({}, {}) is outside the board.", row, col),
+            HandicapError::DuplicatePoint { row, col } =>
+                write!(f, "The handicap stone at ({}, {}) is placed twice.", row, col)
+        }
+    }
+}
+
+impl Error for HandicapError {}
+
+pub struct Handicap;
+
+impl Handicap {
+    /// The star points for a fixed handicap on a 9x9, 13x13 or 19x19 board,
+    /// in row-major order. The first two stones go in the top right and
+    /// bottom left corners, then bottom right and top left, then the sides,
+    /// and odd handicaps from five up put the last stone in the centre.
+    pub fn fixed_points(rows: u32, cols: u32, stones: u32) -> Result<Vec<(u32, u32)>, HandicapError> {
+        if stones < MIN_HANDICAP {
+            return Err(HandicapError::TooFewStones { stones });
+        }
+
+        let edge = match (rows == cols, rows) {
+            (true, 9) => 2,
+            (true, 13) | (true, 19) => 3,
+            _ => return Err(HandicapError::UnsupportedFixedHandicap { rows, cols, stones })
+        };
+        if stones > MAX_FIXED_HANDICAP {
+            return Err(HandicapError::UnsupportedFixedHandicap { rows, cols, stones });
+        }
+
+        let (near, middle, far) = (edge, rows / 2, rows - 1 - edge);
+        let corners = [(near, far), (far, near), (far, far), (near, near)];
+        let left_and_right = [(middle, near), (middle, far)];
+        let top_and_bottom = [(near, middle), (far, middle)];
+
+        let mut points: Vec<(u32, u32)> = corners.iter().take(stones.min(4) as usize).copied().collect();
+        if stones >= 6 {
+            points.extend(left_and_right);
+        }
+        if stones >= 8 {
+            points.extend(top_and_bottom);
+        }
+        if stones % 2 == 1 && stones >= 5 {
+            points.push((middle, middle));
+        }
+        points.sort();
+
+        Ok(points)
+    }
+
+    pub fn komi(handicap_komi: HandicapKomi, stones: u32) -> f32 {
+        match handicap_komi {
+            HandicapKomi::HalfPoint => 0.5,
+            HandicapKomi::PerStone => 0.5 + stones as f32,
+            HandicapKomi::PerStoneAfterFirst => 0.5 + stones.saturating_sub(1) as f32
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_points_19x19() {
+        assert_eq!(Ok(vec![(3, 15), (15, 3)]), Handicap::fixed_points(19, 19, 2));
+        assert_eq!(Ok(vec![(3, 15), (15, 3), (15, 15)]), Handicap::fixed_points(19, 19, 3));
+        assert_eq!(Ok(vec![(3, 3), (3, 15), (9, 9), (15, 3), (15, 15)]), Handicap::fixed_points(19, 19, 5));
+        assert_eq!(
+            Ok(vec![(3, 3), (3, 15), (9, 3), (9, 15), (15, 3), (15, 15)]),
+            Handicap::fixed_points(19, 19, 6)
+        );
+        assert_eq!(
+            Ok(vec![(3, 3), (3, 9), (3, 15), (9, 3), (9, 9), (9, 15), (15, 3), (15, 9), (15, 15)]),
+            Handicap::fixed_points(19, 19, 9)
+        );
+    }
+
+    #[test]
+    fn test_fixed_points_small_boards() {
+        assert_eq!(Ok(vec![(2, 2), (2, 6), (6, 2), (6, 6)]), Handicap::fixed_points(9, 9, 4));
+        assert_eq!(
+            Ok(vec![(3, 3), (3, 9), (6, 3), (6, 6), (6, 9), (9, 3), (9, 9)]),
+            Handicap::fixed_points(13, 13, 7)
+        );
+
+        for size in [9, 13, 19] {
+            for stones in MIN_HANDICAP..=MAX_FIXED_HANDICAP {
+                assert_eq!(stones as usize, Handicap::fixed_points(size, size, stones).unwrap().len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_unsupported_fixed_handicaps() {
+        assert_eq!(Err(HandicapError::TooFewStones { stones: 1 }), Handicap::fixed_points(19, 19, 1));
+        assert_eq!(
+            Err(HandicapError::UnsupportedFixedHandicap { rows: 19, cols: 19, stones: 10 }),
+            Handicap::fixed_points(19, 19, 10)
+        );
+        assert_eq!(
+            Err(HandicapError::UnsupportedFixedHandicap { rows: 15, cols: 15, stones: 4 }),
+            Handicap::fixed_points(15, 15, 4)
+        );
+        assert_eq!(
+            Err(HandicapError::UnsupportedFixedHandicap { rows: 19, cols: 13, stones: 4 }),
+            Handicap::fixed_points(19, 13, 4)
+        );
+    }
+
+    #[test]
+    fn test_handicap_komi() {
+        assert_eq!(0.5, Handicap::komi(HandicapKomi::HalfPoint, 4));
+        assert_eq!(4.5, Handicap::komi(HandicapKomi::PerStone, 4));
+        assert_eq!(3.5, Handicap::komi(HandicapKomi::PerStoneAfterFirst, 4));
+    }
+}
@@ -0,0 +1,383 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use super::go_board::GoBoard;
+use super::go_board::GoPlayer;
+use super::go_board::OutOfBoundsError;
+use super::util::group_liberties_aggregator::GroupLibertiesAggregator;
+
+pub type Territory = HashSet<(u32, u32)>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoringMethod {
+    Area,
+    Territory,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreResult {
+    pub black_score: f32,
+    pub white_score: f32,
+    pub winner: Option<GoPlayer>,
+    pub margin: f32,
+}
+
+impl ScoreResult {
+    pub fn new(black_score: f32, white_score: f32) -> Self {
+        let winner = if black_score > white_score {
+            Some(GoPlayer::BLACK)
+        } else if white_score > black_score {
+            Some(GoPlayer::WHITE)
+        } else {
+            None
+        };
+
+        Self {
+            black_score,
+            white_score,
+            winner,
+            margin: (black_score - white_score).abs(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreComparison {
+    pub area: ScoreResult,
+    pub territory: ScoreResult,
+    pub margin_difference: f32,
+}
+
+impl ScoreComparison {
+    /// `margin_difference` is Black's lead under area scoring minus Black's
+    /// lead under territory scoring.
+    pub fn new(area: ScoreResult, territory: ScoreResult) -> Self {
+        let margin_difference = (area.black_score - area.white_score)
+            - (territory.black_score - territory.white_score);
+
+        Self {
+            area,
+            territory,
+            margin_difference,
+        }
+    }
+
+    pub fn same_winner(&self) -> bool {
+        self.area.winner == self.territory.winner
+    }
+}
+
+pub struct Scorer;
+
+impl Scorer {
+    /// Area scoring (Chinese rules): every stone left on the board plus
+    /// every empty point surrounded only by that player's stones, with komi
+    /// added to White. Dead stones are taken off before counting.
+    pub fn area_score(
+        board: &GoBoard,
+        dead_stones: &[(u32, u32)],
+        komi: f32,
+    ) -> Result<ScoreResult, OutOfBoundsError> {
+        let board = Self::remove_dead_stones(board, dead_stones)?;
+        let territories = Self::get_territories(&board);
+
+        let mut stones = HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]);
+        for piece in board.board_state().iter().flatten().flatten() {
+            *stones.get_mut(piece).unwrap() += 1;
+        }
+
+        let black_area = stones[&GoPlayer::BLACK] + territories[&GoPlayer::BLACK].len();
+        let white_area = stones[&GoPlayer::WHITE] + territories[&GoPlayer::WHITE].len();
+
+        Ok(ScoreResult::new(black_area as f32, white_area as f32 + komi))
+    }
+
+    /// Territory scoring (Japanese/Korean rules): surrounded empty points
+    /// plus prisoners plus the opponent's dead stones, with komi added to
+    /// White. Stones on the board don't count, and a dead stone listed
+    /// more than once is only a prisoner once.
+    pub fn territory_score(
+        board: &GoBoard,
+        dead_stones: &[(u32, u32)],
+        prisoners: &HashMap<GoPlayer, u32>,
+        komi: f32,
+    ) -> Result<ScoreResult, OutOfBoundsError> {
+        let mut captures = prisoners.clone();
+        let unique_dead_stones: HashSet<&(u32, u32)> = dead_stones.iter().collect();
+        for (row, col) in unique_dead_stones {
+            match board.get(*row, *col)? {
+                Some(GoPlayer::BLACK) => *captures.entry(GoPlayer::WHITE).or_insert(0) += 1,
+                Some(GoPlayer::WHITE) => *captures.entry(GoPlayer::BLACK).or_insert(0) += 1,
+                None => (),
+            }
+        }
+
+        let board = Self::remove_dead_stones(board, dead_stones)?;
+        let territories = Self::get_territories(&board);
+
+        let black_points = territories[&GoPlayer::BLACK].len() as u32
+            + captures.get(&GoPlayer::BLACK).unwrap_or(&0);
+        let white_points = territories[&GoPlayer::WHITE].len() as u32
+            + captures.get(&GoPlayer::WHITE).unwrap_or(&0);
+
+        Ok(ScoreResult::new(black_points as f32, white_points as f32 + komi))
+    }
+
+    /// Splits the empty points into regions by flood fill and hands each
+    /// region to the player whose stones are the only ones bordering it.
+    /// Regions touching both colours (or no stones at all) are neutral.
+    pub fn get_territories(board: &GoBoard) -> HashMap<GoPlayer, Territory> {
+        let mut territories =
+            HashMap::from([(GoPlayer::BLACK, Territory::new()), (GoPlayer::WHITE, Territory::new())]);
+        let mut visited = HashSet::new();
+
+        for row in 0..board.board_state().len() as u32 {
+            for col in 0..board.board_state()[0].len() as u32 {
+                if board.board_state()[row as usize][col as usize].is_some()
+                    || visited.contains(&(row, col))
+                {
+                    continue;
+                }
+
+                let (region, bordering_players) = Self::get_empty_region(board, row, col);
+                visited.extend(region.iter().copied());
+
+                if bordering_players.len() == 1 {
+                    let owner = bordering_players.into_iter().next().unwrap();
+                    territories.get_mut(&owner).unwrap().extend(region);
+                }
+            }
+        }
+
+        territories
+    }
+
+    fn get_empty_region(board: &GoBoard, row: u32, col: u32) -> (Territory, HashSet<GoPlayer>) {
+        let mut region = Territory::from([(row, col)]);
+        let mut bordering_players = HashSet::new();
+        let mut stack = vec![(row, col)];
+
+        while let Some((row, col)) = stack.pop() {
+            for (adjacent_row, adjacent_col) in
+                GroupLibertiesAggregator::get_adjacent_points(board, row, col)
+            {
+                match board.board_state()[adjacent_row as usize][adjacent_col as usize] {
+                    Some(player) => {
+                        bordering_players.insert(player);
+                    }
+                    None => {
+                        if region.insert((adjacent_row, adjacent_col)) {
+                            stack.push((adjacent_row, adjacent_col));
+                        }
+                    }
+                }
+            }
+        }
+
+        (region, bordering_players)
+    }
+
+    fn remove_dead_stones(
+        board: &GoBoard,
+        dead_stones: &[(u32, u32)],
+    ) -> Result<GoBoard, OutOfBoundsError> {
+        let mut board = board.clone();
+        for (row, col) in dead_stones {
+            board.check_bounds(*row, *col)?;
+            board.remove(*row, *col);
+        }
+
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_score_empty_board() {
+        let board = GoBoard::new(9);
+
+        let result = Scorer::area_score(&board, &[], 7.5).unwrap();
+
+        assert_eq!(ScoreResult::new(0.0, 7.5), result);
+        assert_eq!(Some(GoPlayer::WHITE), result.winner);
+        assert_eq!(7.5, result.margin);
+    }
+
+    #[test]
+    fn test_area_score_split_board() {
+        let mut board = GoBoard::new(4);
+
+        //| |X|O| |
+        //| |X|O| |
+        //| |X|O| |
+        //| |X|O| |
+
+        for row in 0..4 {
+            board.place(row, 1, &GoPlayer::BLACK);
+            board.place(row, 2, &GoPlayer::WHITE);
+        }
+
+        let territories = Scorer::get_territories(&board);
+        assert_eq!(
+            Territory::from([(0, 0), (1, 0), (2, 0), (3, 0)]),
+            territories[&GoPlayer::BLACK]
+        );
+        assert_eq!(
+            Territory::from([(0, 3), (1, 3), (2, 3), (3, 3)]),
+            territories[&GoPlayer::WHITE]
+        );
+
+        let result = Scorer::area_score(&board, &[], 0.0).unwrap();
+
+        assert_eq!(8.0, result.black_score);
+        assert_eq!(8.0, result.white_score);
+        assert_eq!(None, result.winner);
+        assert_eq!(0.0, result.margin);
+    }
+
+    #[test]
+    fn test_area_score_rectangular_board() {
+        let mut board = GoBoard::with_dimensions(2, 6).unwrap();
+
+        //| |X|O| | | |
+        //| |X|O| | | |
+
+        for row in 0..2 {
+            board.place(row, 1, &GoPlayer::BLACK);
+            board.place(row, 2, &GoPlayer::WHITE);
+        }
+
+        let territories = Scorer::get_territories(&board);
+        assert_eq!(Territory::from([(0, 0), (1, 0)]), territories[&GoPlayer::BLACK]);
+        assert_eq!(6, territories[&GoPlayer::WHITE].len());
+
+        let result = Scorer::area_score(&board, &[], 0.0).unwrap();
+
+        assert_eq!(ScoreResult::new(4.0, 8.0), result);
+    }
+
+    #[test]
+    fn test_area_score_neutral_points() {
+        let mut board = GoBoard::new(3);
+
+        //|X| |O|
+        //| | | |
+        //| | | |
+
+        board.place(0, 0, &GoPlayer::BLACK);
+        board.place(0, 2, &GoPlayer::WHITE);
+
+        let territories = Scorer::get_territories(&board);
+        assert!(territories[&GoPlayer::BLACK].is_empty());
+        assert!(territories[&GoPlayer::WHITE].is_empty());
+
+        let result = Scorer::area_score(&board, &[], 0.5).unwrap();
+
+        assert_eq!(ScoreResult::new(1.0, 1.5), result);
+    }
+
+    #[test]
+    fn test_area_score_with_dead_stones() {
+        let mut board = GoBoard::new(4);
+
+        //| |X|O| |
+        //| |X|O|X|
+        //| |X|O| |
+        //| |X|O| |
+
+        for row in 0..4 {
+            board.place(row, 1, &GoPlayer::BLACK);
+            board.place(row, 2, &GoPlayer::WHITE);
+        }
+        board.place(1, 3, &GoPlayer::BLACK);
+
+        let result = Scorer::area_score(&board, &[], 6.5).unwrap();
+        assert_eq!(ScoreResult::new(9.0, 10.5), result);
+
+        let result = Scorer::area_score(&board, &[(1, 3)], 6.5).unwrap();
+
+        assert_eq!(ScoreResult::new(8.0, 14.5), result);
+        assert_eq!(Some(GoPlayer::WHITE), result.winner);
+        assert_eq!(6.5, result.margin);
+    }
+
+    #[test]
+    fn test_territory_score_with_prisoners_and_dead_stones() {
+        let mut board = GoBoard::new(4);
+
+        //| |X|O| |
+        //| |X|O|X|
+        //| |X|O| |
+        //| |X|O| |
+
+        for row in 0..4 {
+            board.place(row, 1, &GoPlayer::BLACK);
+            board.place(row, 2, &GoPlayer::WHITE);
+        }
+        board.place(1, 3, &GoPlayer::BLACK);
+
+        let prisoners = HashMap::from([(GoPlayer::BLACK, 3), (GoPlayer::WHITE, 1)]);
+
+        let result = Scorer::territory_score(&board, &[(1, 3)], &prisoners, 6.5).unwrap();
+
+        assert_eq!(ScoreResult::new(7.0, 12.5), result);
+        assert_eq!(Some(GoPlayer::WHITE), result.winner);
+        assert_eq!(5.5, result.margin);
+    }
+
+    #[test]
+    fn test_territory_score_counts_repeated_dead_stone_once() {
+        let mut board = GoBoard::new(4);
+
+        //| |X|O| |
+        //| |X|O|X|
+        //| |X|O| |
+        //| |X|O| |
+
+        for row in 0..4 {
+            board.place(row, 1, &GoPlayer::BLACK);
+            board.place(row, 2, &GoPlayer::WHITE);
+        }
+        board.place(1, 3, &GoPlayer::BLACK);
+
+        let prisoners = HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]);
+
+        let result = Scorer::territory_score(&board, &[(1, 3), (1, 3), (1, 3)], &prisoners, 0.0).unwrap();
+
+        assert_eq!(Scorer::territory_score(&board, &[(1, 3)], &prisoners, 0.0).unwrap(), result);
+        assert_eq!(ScoreResult::new(4.0, 5.0), result);
+    }
+
+    #[test]
+    fn test_score_comparison() {
+        let comparison =
+            ScoreComparison::new(ScoreResult::new(40.0, 41.5), ScoreResult::new(12.0, 11.5));
+
+        assert_eq!(-2.0, comparison.margin_difference);
+        assert!(!comparison.same_winner());
+
+        let comparison =
+            ScoreComparison::new(ScoreResult::new(40.0, 38.5), ScoreResult::new(12.0, 10.5));
+
+        assert_eq!(0.0, comparison.margin_difference);
+        assert!(comparison.same_winner());
+    }
+
+    #[test]
+    fn test_dead_stone_out_of_bounds() {
+        let board = GoBoard::new(4);
+        let prisoners = HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]);
+
+        assert_eq!(
+            Err(OutOfBoundsError { row: 4, col: 0 }),
+            Scorer::area_score(&board, &[(4, 0)], 6.5)
+        );
+        assert_eq!(
+            Err(OutOfBoundsError { row: 0, col: 7 }),
+            Scorer::territory_score(&board, &[(0, 7)], &prisoners, 6.5)
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use super::go_board::GoPlayer;
+
+/// Stones put on or taken off the board outside of play, the way SGF's AB,
+/// AW and AE properties do, and whose turn it is afterwards if that
+/// changes. Points are emptied first, then Black's and White's stones are
+/// put down.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Setup {
+    pub black_stones: Vec<(u32, u32)>,
+    pub white_stones: Vec<(u32, u32)>,
+    pub empty_points: Vec<(u32, u32)>,
+    pub player: Option<GoPlayer>
+}
+
+impl Setup {
+    /// Every point the setup changes with what it leaves there, in the
+    /// order they are applied.
+    pub fn changes(&self) -> Vec<((u32, u32), Option<GoPlayer>)> {
+        let empty = self.empty_points.iter().map(|point| (*point, None));
+        let black = self.black_stones.iter().map(|point| (*point, Some(GoPlayer::BLACK)));
+        let white = self.white_stones.iter().map(|point| (*point, Some(GoPlayer::WHITE)));
+
+        empty.chain(black).chain(white).collect()
+    }
+}
@@ -1 +1,3 @@
+pub mod benson;
+pub mod dead_stone_estimator;
 pub mod group_liberties_aggregator;
@@ -0,0 +1,139 @@
+use super::super::bitboard::Bitboard;
+use super::super::go_board::GoBoard;
+use super::super::go_board::GoPlayer;
+
+/// What Benson's algorithm proves for one player: the stones that can't
+/// be captured whatever the opponent plays, and the regions they enclose,
+/// where the opponent can never make a living group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnconditionalLife {
+    pub alive_stones: Bitboard,
+    pub safe_regions: Bitboard
+}
+
+pub struct BensonAlgorithm;
+
+impl BensonAlgorithm {
+    /// Splits the points that aren't the player's stones into regions and
+    /// calls a region vital to a chain when all of its empty points are
+    /// liberties of that chain. Chains with fewer than two vital regions
+    /// can't be unconditionally alive, and neither can the regions bordered
+    /// by such chains, so both are dropped until nothing changes. The
+    /// chains left are unconditionally alive, even if the opponent were to
+    /// play every move.
+    pub fn unconditional_life(board: &GoBoard, player: &GoPlayer) -> UnconditionalLife {
+        let (rows, cols) = (board.rows(), board.cols());
+        let stones = *board.stones(player);
+        let empty = board.empty_points();
+
+        let mut chains = stones.components();
+        let mut regions: Vec<Bitboard> = (&Bitboard::full(rows, cols) - &stones)
+            .components()
+            .into_iter()
+            .filter(|region| !(&region.neighbours() & &stones).is_empty())
+            .collect();
+
+        let alive_stones = loop {
+            let counts = (chains.len(), regions.len());
+
+            chains.retain(|chain| {
+                let liberties = &chain.neighbours() & &empty;
+                regions.iter().filter(|region| Self::is_vital(region, &liberties, &empty)).count() >= 2
+            });
+            let alive_stones = chains.iter().fold(Bitboard::new(rows, cols), |alive, chain| &alive | chain);
+
+            regions.retain(|region| (&(&region.neighbours() & &stones) - &alive_stones).is_empty());
+
+            if counts == (chains.len(), regions.len()) {
+                break alive_stones;
+            }
+        };
+
+        let safe_regions = regions
+            .iter()
+            .filter(|region| {
+                chains.iter().any(|chain| Self::is_vital(region, &(&chain.neighbours() & &empty), &empty))
+            })
+            .fold(Bitboard::new(rows, cols), |safe, region| &safe | region);
+
+        UnconditionalLife { alive_stones, safe_regions }
+    }
+
+    fn is_vital(region: &Bitboard, liberties: &Bitboard, empty: &Bitboard) -> bool {
+        (&(region & empty) - liberties).is_empty() && !(region & liberties).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_eyes_are_alive() {
+        let board = GoBoard::from_rows(&[
+            ".X.X.",
+            "XXXXX",
+            ".....",
+            "..O..",
+            "....."
+        ]);
+
+        let life = BensonAlgorithm::unconditional_life(&board, &GoPlayer::BLACK);
+
+        assert_eq!(board.stones(&GoPlayer::BLACK), &life.alive_stones);
+        assert_eq!(vec![(0, 0), (0, 2), (0, 4)], life.safe_regions.points());
+
+        let life = BensonAlgorithm::unconditional_life(&board, &GoPlayer::WHITE);
+        assert!(life.alive_stones.is_empty());
+        assert!(life.safe_regions.is_empty());
+    }
+
+    #[test]
+    fn test_one_eye_is_not_alive() {
+        let board = GoBoard::from_rows(&[
+            ".X...",
+            "XX...",
+            ".....",
+            ".....",
+            "....."
+        ]);
+
+        let life = BensonAlgorithm::unconditional_life(&board, &GoPlayer::BLACK);
+
+        assert!(life.alive_stones.is_empty());
+        assert!(life.safe_regions.is_empty());
+    }
+
+    #[test]
+    fn test_eye_with_enemy_stone_is_still_vital() {
+        let board = GoBoard::from_rows(&[
+            "O.X.X",
+            ".XXXX",
+            "XX...",
+            ".....",
+            "....."
+        ]);
+
+        let life = BensonAlgorithm::unconditional_life(&board, &GoPlayer::BLACK);
+
+        assert_eq!(board.stones(&GoPlayer::BLACK), &life.alive_stones);
+        assert_eq!(vec![(0, 0), (0, 1), (0, 3), (1, 0)], life.safe_regions.points());
+    }
+
+    #[test]
+    fn test_eye_space_with_inner_points_is_not_vital() {
+        //The opponent could still make a living group in the big space
+        let board = GoBoard::from_rows(&[
+            "...X.",
+            "...X.",
+            "XXXXX",
+            ".....",
+            "....."
+        ]);
+
+        let life = BensonAlgorithm::unconditional_life(&board, &GoPlayer::BLACK);
+
+        assert!(life.alive_stones.is_empty());
+        assert!(life.safe_regions.is_empty());
+    }
+}
@@ -0,0 +1,160 @@
+use super::super::bitboard::Bitboard;
+use super::super::go_board::GoBoard;
+use super::super::go_board::GoPlayer;
+use super::benson::BensonAlgorithm;
+
+/// How far a stone's influence reaches, halving with every step.
+const INFLUENCE_RANGE: u32 = 3;
+const STONE_INFLUENCE: i32 = 8;
+
+pub struct DeadStoneEstimator;
+
+impl DeadStoneEstimator {
+    /// Guesses which stones are dead at the end of a game, as a starting
+    /// point for the players to correct. Stones inside a region Benson's
+    /// algorithm proves safe for the opponent are certainly dead and chains
+    /// it proves alive are never marked. Every other chain is judged by the
+    /// influence of the stones around its liberties: a chain whose
+    /// liberties the opponent controls is taken as dead. Taking dead stones
+    /// off can weaken the chains next to them, so this repeats until no
+    /// more are found.
+    pub fn estimate(board: &GoBoard) -> Vec<(u32, u32)> {
+        let (rows, cols) = (board.rows(), board.cols());
+        let black_life = BensonAlgorithm::unconditional_life(board, &GoPlayer::BLACK);
+        let white_life = BensonAlgorithm::unconditional_life(board, &GoPlayer::WHITE);
+
+        let proven_alive = &black_life.alive_stones | &white_life.alive_stones;
+        let mut dead_stones = &(board.stones(&GoPlayer::BLACK) & &white_life.safe_regions)
+            | &(board.stones(&GoPlayer::WHITE) & &black_life.safe_regions);
+
+        let empty = board.empty_points();
+        loop {
+            let influence = Self::influence(board, &dead_stones);
+            let mut newly_dead = Bitboard::new(rows, cols);
+
+            for player in [GoPlayer::BLACK, GoPlayer::WHITE] {
+                let sign = match player {
+                    GoPlayer::BLACK => 1,
+                    GoPlayer::WHITE => -1
+                };
+
+                for chain in (board.stones(&player) - &dead_stones).components() {
+                    if !(&chain & &proven_alive).is_empty() {
+                        continue;
+                    }
+
+                    let control: i32 = (&chain.neighbours() & &empty)
+                        .points()
+                        .iter()
+                        .map(|(row, col)| sign * influence[(row * cols + col) as usize])
+                        .sum();
+                    if control < 0 {
+                        newly_dead = &newly_dead | &chain;
+                    }
+                }
+            }
+
+            if newly_dead.is_empty() {
+                return dead_stones.points();
+            }
+            dead_stones = &dead_stones | &newly_dead;
+        }
+    }
+
+    /// Black's influence minus White's for every point in row-major order,
+    /// leaving out the stones taken as dead.
+    fn influence(board: &GoBoard, dead_stones: &Bitboard) -> Vec<i32> {
+        let (rows, cols) = (board.rows(), board.cols());
+        let mut influence = vec![0; (rows * cols) as usize];
+
+        for (player, sign) in [(GoPlayer::BLACK, 1), (GoPlayer::WHITE, -1)] {
+            for (stone_row, stone_col) in (board.stones(&player) - dead_stones).points() {
+                let first_row = stone_row.saturating_sub(INFLUENCE_RANGE);
+                let last_row = (stone_row + INFLUENCE_RANGE).min(rows - 1);
+                let first_col = stone_col.saturating_sub(INFLUENCE_RANGE);
+                let last_col = (stone_col + INFLUENCE_RANGE).min(cols - 1);
+
+                for row in first_row..=last_row {
+                    for col in first_col..=last_col {
+                        let distance = row.abs_diff(stone_row) + col.abs_diff(stone_col);
+                        if distance <= INFLUENCE_RANGE {
+                            influence[(row * cols + col) as usize] += sign * (STONE_INFLUENCE >> distance);
+                        }
+                    }
+                }
+            }
+        }
+
+        influence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_board() {
+        assert!(DeadStoneEstimator::estimate(&GoBoard::new(9)).is_empty());
+    }
+
+    #[test]
+    fn test_invading_stones_are_dead() {
+        let board = GoBoard::from_rows(&[
+            "OX.O.",
+            ".X.O.",
+            "XX.OX",
+            ".X.O.",
+            ".X.O."
+        ]);
+
+        assert_eq!(vec![(0, 0), (2, 4)], DeadStoneEstimator::estimate(&board));
+    }
+
+    #[test]
+    fn test_stones_in_safe_region_are_dead() {
+        let board = GoBoard::from_rows(&[
+            "O.X.X....",
+            ".XXXX....",
+            "XX.......",
+            ".........",
+            "........."
+        ]);
+
+        let life = BensonAlgorithm::unconditional_life(&board, &GoPlayer::BLACK);
+        assert!(life.safe_regions.contains(0, 0));
+
+        assert_eq!(vec![(0, 0)], DeadStoneEstimator::estimate(&board));
+    }
+
+    #[test]
+    fn test_proven_alive_chains_are_kept() {
+        //Black's two-eyed group is surrounded by strong White walls
+        let board = GoBoard::from_rows(&[
+            ".X.XO...",
+            "XXXXO...",
+            "OOOOO...",
+            "OOOOO...",
+            "........"
+        ]);
+
+        assert!(DeadStoneEstimator::estimate(&board).is_empty());
+    }
+
+    #[test]
+    fn test_territory_with_a_dead_group() {
+        let board = GoBoard::from_rows(&[
+            "..X.O....",
+            "..X.O....",
+            "XXX.O.X..",
+            "...OO....",
+            "OOOO.....",
+            ".........",
+            ".........",
+            ".........",
+            "........."
+        ]);
+
+        assert_eq!(vec![(2, 6)], DeadStoneEstimator::estimate(&board));
+    }
+}
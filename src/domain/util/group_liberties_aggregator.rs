@@ -11,7 +11,38 @@ pub type WhiteGroups = Vec<Group>;
 
 pub struct GroupLibertiesAggregator;
 
+/// One bit per point of the board, row after row.
+struct VisitedPoints {
+    cols: usize,
+    bits: Vec<u64>,
+}
+
+impl VisitedPoints {
+    fn new(board: &GoBoard) -> Self {
+        let points = board.board_state().len() * board.board_state().first().map_or(0, |cols| cols.len());
+
+        Self {
+            cols: board.board_state().first().map_or(0, |cols| cols.len()),
+            bits: vec![0; points.div_ceil(64)],
+        }
+    }
+
+    /// Marks the point as visited, returning whether it wasn't already.
+    fn visit(&mut self, row: u32, col: u32) -> bool {
+        let index = row as usize * self.cols + col as usize;
+        let mask = 1 << (index % 64);
+
+        let unvisited = self.bits[index / 64] & mask == 0;
+        self.bits[index / 64] |= mask;
+
+        unvisited
+    }
+}
+
 impl GroupLibertiesAggregator {
+    /// Works out every group and its liberties from scratch. Play relies on
+    /// the chains `GoBoard` tracks as stones come and go; this is kept as the
+    /// straightforward reference the tracked chains are checked against.
     pub fn get_group_liberties(board: &GoBoard) -> HashMap<GoPlayer, Vec<(Group, Liberties)>> {
         let mut liberties =
             HashMap::from([(GoPlayer::BLACK, Vec::new()), (GoPlayer::WHITE, Vec::new())]);
@@ -25,31 +56,20 @@ impl GroupLibertiesAggregator {
     }
 
     fn get_piece_groups(board: &GoBoard) -> (BlackGroups, WhiteGroups) {
-        let mut black_groups = BlackGroups::new();
-        let mut white_groups = WhiteGroups::new();
-
-        let mut cloned_board = board.clone();
+        let mut visited = VisitedPoints::new(board);
 
-        white_groups.append(&mut Self::get_groups(&mut cloned_board, &GoPlayer::WHITE));
-        black_groups.append(&mut Self::get_groups(&mut cloned_board, &GoPlayer::BLACK));
+        let white_groups = Self::get_groups(board, &GoPlayer::WHITE, &mut visited);
+        let black_groups = Self::get_groups(board, &GoPlayer::BLACK, &mut visited);
 
         (black_groups, white_groups)
     }
 
-    fn get_groups(board: &mut GoBoard, player: &GoPlayer) -> Vec<Group> {
+    fn get_groups(board: &GoBoard, player: &GoPlayer, visited: &mut VisitedPoints) -> Vec<Group> {
         let mut groups = Vec::new();
-        for row in 0..board.board_state.len() {
-            for col in 0..board.board_state[0].len() {
-                if let Some(i) = board.board_state[row as usize][col as usize] {
-                    match i == player.to_owned() {
-                        true => {
-                            let mut group = Group::new();
-                            Self::get_group(board, &player, row as i32, col as i32, &mut group);
-
-                            groups.push(group);
-                        }
-                        _ => continue,
-                    }
+        for row in 0..board.board_state().len() as u32 {
+            for col in 0..board.board_state()[0].len() as u32 {
+                if board.board_state()[row as usize][col as usize] == Some(*player) && visited.visit(row, col) {
+                    groups.push(Self::get_group(board, player, row, col, visited));
                 }
             }
         }
@@ -57,35 +77,50 @@ impl GroupLibertiesAggregator {
         groups
     }
 
+    /// Collects the group around (row, col) with an explicit stack, so big
+    /// groups can't overflow the call stack. The starting point must already
+    /// be marked as visited.
     fn get_group(
-        board: &mut GoBoard,
+        board: &GoBoard,
         player: &GoPlayer,
-        row: i32,
-        col: i32,
-        current_group: &mut Group,
-    ) {
-        if row < 0
-            || row >= board.board_state.len() as i32
-            || col < 0
-            || col >= board.board_state[0].len() as i32
-        {
-            return;
+        row: u32,
+        col: u32,
+        visited: &mut VisitedPoints,
+    ) -> Group {
+        let mut group = Group::from([(row, col)]);
+        let mut stack = vec![(row, col)];
+
+        while let Some((row, col)) = stack.pop() {
+            for (adjacent_row, adjacent_col) in Self::get_adjacent_points(board, row, col) {
+                if board.board_state()[adjacent_row as usize][adjacent_col as usize] == Some(*player)
+                    && visited.visit(adjacent_row, adjacent_col)
+                {
+                    group.insert((adjacent_row, adjacent_col));
+                    stack.push((adjacent_row, adjacent_col));
+                }
+            }
         }
 
-        if let Some(i) = board.board_state[row as usize][col as usize] {
-            match i == player.to_owned() {
-                true => {
-                    current_group.insert((row as u32, col as u32));
-                    board.remove(row as u32, col as u32);
+        group
+    }
 
-                    Self::get_group(board, player, row + 1, col, current_group);
-                    Self::get_group(board, player, row - 1, col, current_group);
-                    Self::get_group(board, player, row, col + 1, current_group);
-                    Self::get_group(board, player, row, col - 1, current_group);
-                }
-                false => return,
-            }
+    pub fn get_adjacent_points(board: &GoBoard, row: u32, col: u32) -> Vec<(u32, u32)> {
+        let mut adjacent_points = Vec::with_capacity(4);
+
+        if row > 0 {
+            adjacent_points.push((row - 1, col));
         }
+        if row < (board.board_state().len() - 1) as u32 {
+            adjacent_points.push((row + 1, col));
+        }
+        if col > 0 {
+            adjacent_points.push((row, col - 1));
+        }
+        if col < (board.board_state()[0].len() - 1) as u32 {
+            adjacent_points.push((row, col + 1));
+        }
+
+        adjacent_points
     }
 
     fn insert_liberties(
@@ -97,24 +132,10 @@ impl GroupLibertiesAggregator {
         for group in groups {
             let mut group_liberties = Liberties::new();
             for (row, col) in group.iter() {
-                let row = row.to_owned();
-                let col = col.to_owned();
-
-                if row > 0 && board.board_state[(row - 1) as usize][col as usize].is_none() {
-                    group_liberties.insert((row - 1, col));
-                }
-                if (row < (board.board_state.len() - 1) as u32
-                    && board.board_state[(row + 1) as usize][col as usize].is_none())
-                {
-                    group_liberties.insert((row + 1, col));
-                }
-                if col > 0 && board.board_state[row as usize][(col - 1) as usize].is_none() {
-                    group_liberties.insert((row, col - 1));
-                }
-                if (col < (board.board_state[0].len() - 1) as u32
-                    && board.board_state[row as usize][(col + 1) as usize].is_none())
-                {
-                    group_liberties.insert((row, col + 1));
+                for (adjacent_row, adjacent_col) in Self::get_adjacent_points(board, *row, *col) {
+                    if board.board_state()[adjacent_row as usize][adjacent_col as usize].is_none() {
+                        group_liberties.insert((adjacent_row, adjacent_col));
+                    }
                 }
             }
             let mut list = liberties.get(player).unwrap().to_owned();
@@ -366,4 +387,48 @@ mod group_aggregator_tests {
 
         assert_eq!(old_board, board);
     }
+
+    #[test]
+    fn test_get_piece_groups_filled_52x52_board() {
+        let mut board = GoBoard::with_dimensions(52, 52).unwrap();
+        for row in 0..52 {
+            for col in 0..52 {
+                board.place(row, col, &GoPlayer::BLACK);
+            }
+        }
+
+        let old_board = board.clone();
+
+        let groups = super::GroupLibertiesAggregator::get_piece_groups(&board);
+
+        assert_eq!(1, groups.0.len());
+        assert_eq!(52 * 52, groups.0[0].len());
+        assert_eq!(0, groups.1.len());
+
+        let liberties = GroupLibertiesAggregator::get_group_liberties(&board);
+        assert!(liberties[&GoPlayer::BLACK][0].1.is_empty());
+
+        assert_eq!(old_board, board);
+    }
+
+    #[test]
+    fn test_get_piece_groups_52x52_snake() {
+        let mut board = GoBoard::with_dimensions(52, 52).unwrap();
+
+        //One group winding through every other row, joined at alternating ends
+        for row in (0..52).step_by(2) {
+            for col in 0..52 {
+                board.place(row, col, &GoPlayer::WHITE);
+            }
+            if row + 2 < 52 {
+                let joining_col = if row % 4 == 0 { 51 } else { 0 };
+                board.place(row + 1, joining_col, &GoPlayer::WHITE);
+            }
+        }
+
+        let groups = super::GroupLibertiesAggregator::get_piece_groups(&board);
+
+        assert_eq!(1, groups.1.len());
+        assert_eq!(26 * 52 + 25, groups.1[0].len());
+    }
 }
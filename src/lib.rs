@@ -0,0 +1,4 @@
+pub mod domain;
+pub mod review;
+pub mod sgf;
+pub mod use_cases;
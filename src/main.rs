@@ -1,4 +1 @@
-mod domain;
-mod use_cases;
-
 fn main() {}
@@ -0,0 +1,595 @@
+use crate::domain::game_status::{GameResult, GameStatus};
+use crate::domain::go_board::GoPlayer;
+use crate::domain::go_game::{GoGame, GoMove};
+use crate::domain::setup::Setup;
+use crate::use_cases::pass_turn::pass_turn_input::PassTurnInput;
+use crate::use_cases::pass_turn::pass_turn_requester::PassTurnRequester;
+use crate::use_cases::place_piece::place_piece_error::PlacePieceError;
+use crate::use_cases::place_piece::place_piece_input::PlacePieceInput;
+use crate::use_cases::place_piece::place_piece_requester::PlacePieceRequester;
+use crate::use_cases::resign::resign_input::ResignInput;
+use crate::use_cases::resign::resign_requester::ResignRequester;
+use crate::use_cases::scoring_phase::scoring_phase_input::{ScoringAction, ScoringPhaseInput};
+use crate::use_cases::scoring_phase::scoring_phase_requester::ScoringPhaseRequester;
+
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameTreeNode {
+    pub setup: Option<Setup>,
+    pub chosen_move: Option<(GoPlayer, GoMove)>,
+    pub parent: Option<NodeId>,
+    pub children: Vec<NodeId>,
+}
+
+/// A tree of variations rooted at a starting `GoGame`. Every node other than
+/// the root holds the move leading to it, a setup of the board before that
+/// move, or both, while the root holds the setup the game started from, if
+/// any. A node's first child continues the main line and the other children
+/// are variations. The tree keeps the game for
+/// the current node up to date while navigating by playing and undoing moves.
+///
+/// The tree is a review tool, so moves are played for whichever player is
+/// given rather than following the turn order, with the node's setup handing
+/// them the move, and play is resumed after two passes.
+#[derive(Clone)]
+pub struct GameTree {
+    nodes: Vec<GameTreeNode>,
+    current: NodeId,
+    game: GoGame,
+    /// The `resumed_at` each resumption of play on the way to the current
+    /// node replaced, so that going back can take it back.
+    resumptions: Vec<Option<usize>>,
+}
+
+impl GameTree {
+    pub const ROOT: NodeId = 0;
+
+    /// Starts a tree from the game. The moves and setups it has already
+    /// been through are taken back and replayed as the main line, leaving
+    /// the tree at its last node. A finished game is finished again the way
+    /// it ended.
+    pub fn new(mut game: GoGame) -> Self {
+        let (status, dead_stones) = (game.status, game.dead_stone_marking.sorted_dead_stones());
+
+        let mut history = Vec::new();
+        while !game.move_history.is_empty() || game.setup_history.len() > 1 {
+            match game.unapply_setup() {
+                Some(setup) => history.push((Some(setup), None)),
+                None => {
+                    let record = game.unapply().unwrap();
+                    history.push((None, Some((record.player, record.chosen_move))));
+                }
+            }
+        }
+
+        let mut tree = Self {
+            nodes: vec![GameTreeNode {
+                setup: game.setup_history.first().map(|record| record.setup.clone()),
+                chosen_move: None,
+                parent: None,
+                children: Vec::new(),
+            }],
+            current: Self::ROOT,
+            game,
+            resumptions: Vec::new(),
+        };
+
+        for (setup, chosen_move) in history.into_iter().rev() {
+            // The game has been through all of these already.
+            tree.add_node(setup, chosen_move).unwrap();
+        }
+
+        if let GameStatus::Finished { result } = status {
+            tree.finish(result, dead_stones);
+        }
+
+        tree
+    }
+
+    pub fn game(&self) -> &GoGame {
+        &self.game
+    }
+
+    pub fn current(&self) -> NodeId {
+        self.current
+    }
+
+    pub fn node(&self, id: NodeId) -> Option<&GameTreeNode> {
+        self.nodes.get(id)
+    }
+
+    /// Plays a move from the current node and moves to it. If the move was
+    /// already played from here the existing node is reused, otherwise it
+    /// becomes a new variation after the existing ones.
+    pub fn play(&mut self, player: GoPlayer, chosen_move: GoMove) -> Result<NodeId, PlacePieceError> {
+        self.add_node(None, Some((player, chosen_move)))
+    }
+
+    /// Sets up the board from the current node, then plays the move if one
+    /// is given, and moves to the new node. Like `play`, an identical node
+    /// is reused.
+    pub fn set_up(
+        &mut self,
+        setup: Setup,
+        chosen_move: Option<(GoPlayer, GoMove)>,
+    ) -> Result<NodeId, PlacePieceError> {
+        self.add_node(Some(setup), chosen_move)
+    }
+
+    fn add_node(
+        &mut self,
+        setup: Option<Setup>,
+        chosen_move: Option<(GoPlayer, GoMove)>,
+    ) -> Result<NodeId, PlacePieceError> {
+        // A move out of turn hands the move to its player first, the way
+        // PL does in SGF.
+        let mut setup = setup;
+        if let Some((player, _)) = chosen_move {
+            let player_to_move = setup
+                .as_ref()
+                .and_then(|setup| setup.player)
+                .unwrap_or(self.game.current_player);
+            if player != player_to_move {
+                setup.get_or_insert_with(Setup::default).player = Some(player);
+            }
+        }
+
+        let existing_child = self.nodes[self.current]
+            .children
+            .iter()
+            .copied()
+            .find(|child| self.nodes[*child].setup == setup && self.nodes[*child].chosen_move == chosen_move);
+
+        self.enter(&setup, chosen_move)?;
+
+        if let Some(child) = existing_child {
+            self.current = child;
+            return Ok(child);
+        }
+
+        let child = self.nodes.len();
+        self.nodes.push(GameTreeNode {
+            setup,
+            chosen_move,
+            parent: Some(self.current),
+            children: Vec::new(),
+        });
+        self.nodes[self.current].children.push(child);
+        self.current = child;
+
+        Ok(child)
+    }
+
+    pub fn to_parent(&mut self) -> bool {
+        match self.nodes[self.current].parent {
+            Some(parent) => {
+                // Nodes are only entered through the tree, so the current
+                // node's move is the last thing done to the game.
+                let node = &self.nodes[self.current];
+                if node.chosen_move.is_some() {
+                    assert!(self.game.unapply().is_some(), "Couldn't undo the move leading to node {}", self.current);
+                    if self.game.resumed_at == Some(self.game.move_history.len()) {
+                        let resumed_at = self.resumptions.pop().unwrap();
+                        self.game.unresume_play(resumed_at);
+                    }
+                }
+                if node.setup.is_some() {
+                    assert!(self.game.unapply_setup().is_some(), "Couldn't undo the setup of node {}", self.current);
+                }
+                self.current = parent;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves to the child at `index`; index 0 follows the main line.
+    pub fn to_child(&mut self, index: usize) -> bool {
+        match self.nodes[self.current].children.get(index).copied() {
+            Some(child) => {
+                let node = &self.nodes[child];
+                let (setup, chosen_move) = (node.setup.clone(), node.chosen_move);
+                // Every node in the tree was legal when it was added.
+                self.enter(&setup, chosen_move).unwrap();
+                self.current = child;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn to_next_sibling(&mut self) -> bool {
+        self.move_to_sibling(1)
+    }
+
+    pub fn to_previous_sibling(&mut self) -> bool {
+        self.move_to_sibling(-1)
+    }
+
+    /// Jumps to any node by going back to the closest common ancestor and
+    /// replaying the moves down to the target. Returns false without moving
+    /// if the target isn't a node of this tree.
+    pub fn go_to(&mut self, target: NodeId) -> bool {
+        let target_path = match self.path_from_root(target) {
+            Some(path) => path,
+            None => return false,
+        };
+        while !target_path.contains(&self.current) {
+            self.to_parent();
+        }
+
+        let depth = target_path.iter().position(|node| *node == self.current).unwrap();
+        for node in target_path[depth + 1..].iter() {
+            let index = self.nodes[self.current]
+                .children
+                .iter()
+                .position(|child| child == node)
+                .unwrap();
+            self.to_child(index);
+        }
+
+        true
+    }
+
+    /// Makes the line leading to the current node the main line by moving
+    /// it in front of its siblings at every branch on the way up.
+    pub fn promote_to_main_line(&mut self) {
+        let mut node = self.current;
+        while let Some(parent) = self.nodes[node].parent {
+            let siblings = &mut self.nodes[parent].children;
+            let index = siblings.iter().position(|child| *child == node).unwrap();
+            let promoted = siblings.remove(index);
+            siblings.insert(0, promoted);
+
+            node = parent;
+        }
+    }
+
+    pub fn main_line(&self) -> Vec<NodeId> {
+        let mut line = vec![Self::ROOT];
+        while let Some(child) = self.nodes[*line.last().unwrap()].children.first() {
+            line.push(*child);
+        }
+
+        line
+    }
+
+    pub fn path_from_root(&self, node: NodeId) -> Option<Vec<NodeId>> {
+        self.nodes.get(node)?;
+
+        let mut path = vec![node];
+        while let Some(parent) = self.nodes[*path.last().unwrap()].parent {
+            path.push(parent);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    fn move_to_sibling(&mut self, offset: isize) -> bool {
+        let parent = match self.nodes[self.current].parent {
+            Some(parent) => parent,
+            None => return false,
+        };
+
+        let siblings = &self.nodes[parent].children;
+        let index = siblings.iter().position(|child| *child == self.current).unwrap();
+        let sibling_index = index as isize + offset;
+        if sibling_index < 0 || sibling_index as usize >= siblings.len() {
+            return false;
+        }
+
+        self.to_parent();
+        self.to_child(sibling_index as usize)
+    }
+
+    /// Applies a node's setup and then its move, leaving the game as it was
+    /// if either is rejected.
+    fn enter(&mut self, setup: &Option<Setup>, chosen_move: Option<(GoPlayer, GoMove)>) -> Result<(), PlacePieceError> {
+        if let Some(setup) = setup {
+            self.game.apply_setup(setup.clone())?;
+        }
+
+        if let Some((player, chosen_move)) = chosen_move {
+            if let Err(error) = self.apply_move(player, chosen_move) {
+                if setup.is_some() {
+                    self.game.unapply_setup();
+                }
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Plays the move, resuming play first if the game is being scored, and
+    /// takes the resumption back if the move is rejected. The node's setup
+    /// has already handed the move to `player`.
+    fn apply_move(&mut self, player: GoPlayer, chosen_move: GoMove) -> Result<(), PlacePieceError> {
+        let resumes = self.game.status == GameStatus::Scoring;
+        if resumes {
+            self.resumptions.push(self.game.resume_play());
+        }
+
+        let result = match chosen_move {
+            GoMove::Place(row, col) => match self.game.place_piece(PlacePieceInput { row, col, player }).error {
+                Some(error) => Err(error),
+                None => Ok(()),
+            },
+            // It is the player's turn, so a pass can only be rejected once
+            // the game is finished.
+            GoMove::Pass => match self.game.pass_turn(PassTurnInput { player }).success {
+                true => Ok(()),
+                false => Err(PlacePieceError::GameOver),
+            },
+        };
+
+        if result.is_err() && resumes {
+            let resumed_at = self.resumptions.pop().unwrap();
+            self.game.unresume_play(resumed_at);
+        }
+
+        result
+    }
+
+    /// Ends the game at the current node with the result it had, through
+    /// the use cases that ended it.
+    fn finish(&mut self, result: GameResult, dead_stones: Vec<(u32, u32)>) {
+        match result {
+            GameResult::Resignation { winner } => {
+                let player = match winner {
+                    GoPlayer::BLACK => GoPlayer::WHITE,
+                    GoPlayer::WHITE => GoPlayer::BLACK,
+                };
+                self.game.resign(ResignInput { player });
+            }
+            // A scored game ended with both players accepting the marking,
+            // which the scoring phase turns back into the same result.
+            GameResult::Score { .. } | GameResult::Draw => {
+                self.game.dead_stone_marking.dead_stones = dead_stones.into_iter().collect();
+                for player in [GoPlayer::BLACK, GoPlayer::WHITE] {
+                    self.game.scoring_phase(ScoringPhaseInput {
+                        player,
+                        action: ScoringAction::Accept,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_play_builds_variations() {
+        let mut tree = GameTree::new(GoGame::new(9));
+
+        let main_move = tree.play(GoPlayer::BLACK, GoMove::Place(2, 2)).unwrap();
+        let main_reply = tree.play(GoPlayer::WHITE, GoMove::Place(6, 6)).unwrap();
+
+        assert!(tree.to_parent());
+        let variation_reply = tree.play(GoPlayer::WHITE, GoMove::Place(2, 6)).unwrap();
+
+        assert_eq!(vec![main_reply, variation_reply], tree.node(main_move).unwrap().children);
+        assert_eq!(vec![GameTree::ROOT, main_move, main_reply], tree.main_line());
+        assert_eq!(Some(GoPlayer::WHITE), tree.game().board.board_state()[2][6]);
+        assert_eq!(None, tree.game().board.board_state()[6][6]);
+
+        //Playing an existing move reuses its node
+        tree.to_parent();
+        assert_eq!(Ok(main_reply), tree.play(GoPlayer::WHITE, GoMove::Place(6, 6)));
+        assert_eq!(2, tree.node(main_move).unwrap().children.len());
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut tree = GameTree::new(GoGame::new(9));
+
+        let first = tree.play(GoPlayer::BLACK, GoMove::Place(2, 2)).unwrap();
+        let second = tree.play(GoPlayer::WHITE, GoMove::Place(6, 6)).unwrap();
+        tree.to_parent();
+        let third = tree.play(GoPlayer::WHITE, GoMove::Pass).unwrap();
+
+        assert!(!tree.to_next_sibling());
+        assert!(tree.to_previous_sibling());
+        assert_eq!(second, tree.current());
+        assert!(!tree.to_previous_sibling());
+        assert!(tree.to_next_sibling());
+        assert_eq!(third, tree.current());
+        assert_eq!(GoPlayer::BLACK, tree.game().current_player);
+
+        assert!(tree.to_parent());
+        assert!(tree.to_parent());
+        assert!(!tree.to_parent());
+        assert_eq!(GoGame::new(9).board, tree.game().board);
+
+        assert!(tree.to_child(0));
+        assert_eq!(first, tree.current());
+        assert!(tree.to_child(1));
+        assert_eq!(third, tree.current());
+        assert!(!tree.to_child(0));
+
+        assert!(tree.go_to(second));
+        assert_eq!(second, tree.current());
+        assert_eq!(
+            vec![(GoPlayer::BLACK, GoMove::Place(2, 2)), (GoPlayer::WHITE, GoMove::Place(6, 6))],
+            tree.game().moves()
+        );
+    }
+
+    #[test]
+    fn test_promote_to_main_line() {
+        let mut tree = GameTree::new(GoGame::new(9));
+
+        let first = tree.play(GoPlayer::BLACK, GoMove::Place(2, 2)).unwrap();
+        let second = tree.play(GoPlayer::WHITE, GoMove::Place(6, 6)).unwrap();
+        tree.go_to(GameTree::ROOT);
+        let alternative = tree.play(GoPlayer::BLACK, GoMove::Place(4, 4)).unwrap();
+        let reply = tree.play(GoPlayer::WHITE, GoMove::Place(2, 2)).unwrap();
+
+        assert_eq!(vec![GameTree::ROOT, first, second], tree.main_line());
+
+        tree.promote_to_main_line();
+
+        assert_eq!(vec![GameTree::ROOT, alternative, reply], tree.main_line());
+        assert_eq!(vec![alternative, first], tree.node(GameTree::ROOT).unwrap().children);
+        assert_eq!(reply, tree.current());
+    }
+
+    #[test]
+    fn test_illegal_move_is_not_added() {
+        let mut tree = GameTree::new(GoGame::new(9));
+        tree.play(GoPlayer::BLACK, GoMove::Place(2, 2)).unwrap();
+
+        let result = tree.play(GoPlayer::WHITE, GoMove::Place(2, 2));
+
+        assert_eq!(
+            Err(PlacePieceError::Occupied {
+                player: GoPlayer::WHITE,
+                occupant: GoPlayer::BLACK,
+                row: 2,
+                col: 2
+            }),
+            result
+        );
+        assert!(tree.node(tree.current()).unwrap().children.is_empty());
+    }
+
+    #[test]
+    fn test_rejected_move_leaves_game_unchanged() {
+        let mut tree = GameTree::new(GoGame::new(9));
+        tree.play(GoPlayer::BLACK, GoMove::Place(2, 2)).unwrap();
+
+        assert!(tree.play(GoPlayer::BLACK, GoMove::Place(2, 2)).is_err());
+        assert_eq!(GoPlayer::WHITE, tree.game().current_player);
+        assert_eq!(vec![(GoPlayer::BLACK, GoMove::Place(2, 2))], tree.game().moves());
+
+        tree.play(GoPlayer::WHITE, GoMove::Pass).unwrap();
+        tree.play(GoPlayer::BLACK, GoMove::Pass).unwrap();
+        assert_eq!(GameStatus::Scoring, tree.game().status);
+
+        assert!(tree.play(GoPlayer::WHITE, GoMove::Place(9, 9)).is_err());
+        assert_eq!(GameStatus::Scoring, tree.game().status);
+        assert_eq!(GoPlayer::WHITE, tree.game().current_player);
+        assert_eq!(3, tree.game().moves().len());
+    }
+
+    #[test]
+    fn test_going_back_restores_status() {
+        let mut tree = GameTree::new(GoGame::new(9));
+        tree.play(GoPlayer::BLACK, GoMove::Pass).unwrap();
+        tree.play(GoPlayer::WHITE, GoMove::Pass).unwrap();
+        tree.play(GoPlayer::BLACK, GoMove::Place(2, 2)).unwrap();
+        assert_eq!(GameStatus::InProgress, tree.game().status);
+
+        assert!(tree.to_parent());
+        assert_eq!(GameStatus::Scoring, tree.game().status);
+        assert!(tree.to_parent());
+        assert_eq!(GameStatus::InProgress, tree.game().status);
+    }
+
+    #[test]
+    fn test_unknown_nodes() {
+        let mut tree = GameTree::new(GoGame::new(9));
+        let first = tree.play(GoPlayer::BLACK, GoMove::Place(2, 2)).unwrap();
+
+        assert_eq!(None, tree.node(first + 1));
+        assert_eq!(None, tree.path_from_root(first + 1));
+        assert!(!tree.go_to(first + 1));
+        assert_eq!(first, tree.current());
+    }
+
+    #[test]
+    fn test_new_keeps_game_history() {
+        let mut game = GoGame::new(9);
+        game.apply_setup(Setup { black_stones: vec![(0, 0)], ..Setup::default() }).unwrap();
+        game.apply(GoMove::Place(2, 2)).unwrap();
+        game.apply_setup(Setup { empty_points: vec![(0, 0)], ..Setup::default() }).unwrap();
+        game.apply(GoMove::Pass).unwrap();
+        let board = game.board.clone();
+
+        let mut tree = GameTree::new(game);
+
+        let main_line = tree.main_line();
+        assert_eq!(4, main_line.len());
+        assert_eq!(main_line[3], tree.current());
+        assert_eq!(board, tree.game().board);
+        assert_eq!(
+            vec![(GoPlayer::BLACK, GoMove::Place(2, 2)), (GoPlayer::WHITE, GoMove::Pass)],
+            tree.game().moves()
+        );
+        assert_eq!(vec![(0, 0)], tree.node(GameTree::ROOT).unwrap().setup.as_ref().unwrap().black_stones);
+        assert_eq!(vec![(0, 0)], tree.node(main_line[2]).unwrap().setup.as_ref().unwrap().empty_points);
+
+        assert!(tree.go_to(GameTree::ROOT));
+        assert!(tree.game().moves().is_empty());
+        assert_eq!(Some(GoPlayer::BLACK), tree.game().board.board_state()[0][0]);
+    }
+
+    #[test]
+    fn test_move_out_of_turn_hands_over_the_move() {
+        let mut tree = GameTree::new(GoGame::new(9));
+        tree.play(GoPlayer::BLACK, GoMove::Place(2, 2)).unwrap();
+
+        let node = tree.play(GoPlayer::BLACK, GoMove::Place(6, 6)).unwrap();
+
+        assert_eq!(Some(GoPlayer::BLACK), tree.node(node).unwrap().setup.as_ref().unwrap().player);
+        assert_eq!(GoPlayer::WHITE, tree.game().setup_history[0].player_before);
+        assert_eq!(GoPlayer::WHITE, tree.game().current_player);
+
+        assert!(tree.to_parent());
+        assert_eq!(GoPlayer::WHITE, tree.game().current_player);
+        assert!(tree.game().setup_history.is_empty());
+        assert!(tree.game().undone_moves.is_empty());
+        assert_eq!(Ok(node), tree.play(GoPlayer::BLACK, GoMove::Place(6, 6)));
+    }
+
+    #[test]
+    fn test_going_back_past_two_resumptions() {
+        let mut tree = GameTree::new(GoGame::new(9));
+        tree.play(GoPlayer::BLACK, GoMove::Pass).unwrap();
+        tree.play(GoPlayer::WHITE, GoMove::Pass).unwrap();
+        tree.play(GoPlayer::BLACK, GoMove::Pass).unwrap();
+        tree.play(GoPlayer::WHITE, GoMove::Pass).unwrap();
+        assert_eq!(Some(2), tree.game().resumed_at);
+        tree.play(GoPlayer::BLACK, GoMove::Place(2, 2)).unwrap();
+        assert_eq!(Some(4), tree.game().resumed_at);
+
+        assert!(tree.to_parent());
+        assert_eq!(GameStatus::Scoring, tree.game().status);
+        assert_eq!(Some(2), tree.game().resumed_at);
+        assert!(tree.to_parent());
+        assert!(tree.to_parent());
+        assert_eq!(GameStatus::Scoring, tree.game().status);
+        assert_eq!(None, tree.game().resumed_at);
+    }
+
+    #[test]
+    fn test_new_keeps_finished_game() {
+        let mut game = GoGame::new(9);
+        game.apply(GoMove::Place(2, 2)).unwrap();
+        game.resign(ResignInput { player: GoPlayer::WHITE });
+
+        let tree = GameTree::new(game);
+
+        assert_eq!(Some(GameResult::Resignation { winner: GoPlayer::BLACK }), tree.game().result());
+
+        let mut game = GoGame::new(5);
+        game.komi = 0.5;
+        for player in [GoPlayer::BLACK, GoPlayer::WHITE] {
+            game.pass_turn(PassTurnInput { player });
+        }
+        for player in [GoPlayer::BLACK, GoPlayer::WHITE] {
+            game.scoring_phase(ScoringPhaseInput { player, action: ScoringAction::Accept });
+        }
+        let status = game.status;
+
+        let tree = GameTree::new(game);
+
+        assert_eq!(status, tree.game().status);
+        assert_eq!(Some(GameResult::Score { winner: GoPlayer::WHITE, margin: 0.5 }), tree.game().result());
+    }
+}
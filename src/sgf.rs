@@ -0,0 +1,6 @@
+pub mod sgf_error;
+pub mod sgf_point;
+pub mod sgf_node;
+pub mod sgf_parser;
+pub mod sgf_reader;
+pub mod sgf_writer;
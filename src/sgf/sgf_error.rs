@@ -0,0 +1,33 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::use_cases::place_piece::place_piece_error::PlacePieceError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SgfError {
+    BoardTooLarge { rows: u32, cols: u32 },
+    UnexpectedCharacter { position: usize, character: char },
+    UnexpectedEndOfInput,
+    NoGameTree,
+    InvalidProperty { identifier: String, value: String },
+    IllegalMove { path: Vec<usize>, error: PlacePieceError }
+}
+
+impl fmt::Display for SgfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SgfError::BoardTooLarge { rows, cols } => write!(f,
+                "A {}x{} board can't be written as SGF, the limit is 52x52.", rows, cols),
+            SgfError::UnexpectedCharacter { position, character } => write!(f,
+                "Unexpected character '{}' at position {}.", character, position),
+            SgfError::UnexpectedEndOfInput => write!(f, "The SGF ended unexpectedly."),
+            SgfError::NoGameTree => write!(f, "The SGF doesn't contain a game tree."),
+            SgfError::InvalidProperty { identifier, value } => write!(f,
+                "The property {}[{}] is invalid.", identifier, value),
+            SgfError::IllegalMove { path, error } => write!(f,
+                "Illegal move at node {:?}: {}", path, error)
+        }
+    }
+}
+
+impl Error for SgfError {}
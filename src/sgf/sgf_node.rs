@@ -0,0 +1,28 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SgfProperty {
+    pub identifier: String,
+    pub values: Vec<String>,
+}
+
+/// A node of an SGF game tree. The main line continues through the first
+/// child and any further children are variations.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SgfNode {
+    pub properties: Vec<SgfProperty>,
+    pub children: Vec<SgfNode>,
+}
+
+impl SgfNode {
+    pub fn get(&self, identifier: &str) -> Option<&[String]> {
+        self.properties
+            .iter()
+            .find(|property| property.identifier == identifier)
+            .map(|property| property.values.as_slice())
+    }
+
+    pub fn get_value(&self, identifier: &str) -> Option<&str> {
+        self.get(identifier)
+            .and_then(|values| values.first())
+            .map(|value| value.as_str())
+    }
+}
@@ -0,0 +1,241 @@
+use super::sgf_error::SgfError;
+use super::sgf_node::{SgfNode, SgfProperty};
+
+pub struct SgfParser {
+    characters: Vec<char>,
+    position: usize,
+}
+
+impl SgfParser {
+    /// Parses an SGF collection and returns the root node of every game
+    /// tree in it.
+    pub fn parse(sgf: &str) -> Result<Vec<SgfNode>, SgfError> {
+        let mut parser = Self {
+            characters: sgf.chars().collect(),
+            position: 0,
+        };
+
+        let mut game_trees = Vec::new();
+        parser.skip_whitespace();
+        while parser.peek().is_some() {
+            game_trees.push(parser.parse_game_tree()?);
+            parser.skip_whitespace();
+        }
+
+        match game_trees.is_empty() {
+            true => Err(SgfError::NoGameTree),
+            false => Ok(game_trees),
+        }
+    }
+
+    fn parse_game_tree(&mut self) -> Result<SgfNode, SgfError> {
+        self.expect('(')?;
+
+        let mut sequence = Vec::new();
+        self.skip_whitespace();
+        while self.peek() == Some(';') {
+            sequence.push(self.parse_node()?);
+            self.skip_whitespace();
+        }
+
+        if sequence.is_empty() {
+            return Err(self.unexpected());
+        }
+
+        let mut variations = Vec::new();
+        while self.peek() == Some('(') {
+            variations.push(self.parse_game_tree()?);
+            self.skip_whitespace();
+        }
+
+        self.expect(')')?;
+
+        // Chain the sequence from the back so the last node owns the
+        // variations and each node owns the one after it.
+        let mut node = sequence.pop().unwrap();
+        node.children = variations;
+        while let Some(mut parent) = sequence.pop() {
+            parent.children.push(node);
+            node = parent;
+        }
+
+        Ok(node)
+    }
+
+    fn parse_node(&mut self) -> Result<SgfNode, SgfError> {
+        self.expect(';')?;
+
+        let mut node = SgfNode::default();
+        self.skip_whitespace();
+        while self.peek().is_some_and(|character| character.is_ascii_alphabetic()) {
+            node.properties.push(self.parse_property()?);
+            self.skip_whitespace();
+        }
+
+        Ok(node)
+    }
+
+    fn parse_property(&mut self) -> Result<SgfProperty, SgfError> {
+        let mut identifier = String::new();
+        while let Some(character) = self.peek().filter(|character| character.is_ascii_alphabetic()) {
+            // FF[3] allowed lower case letters inside identifiers
+            // (e.g. "AddBlack"), only the upper case ones are significant.
+            if character.is_ascii_uppercase() {
+                identifier.push(character);
+            }
+            self.position += 1;
+        }
+
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        while self.peek() == Some('[') {
+            values.push(self.parse_value()?);
+            self.skip_whitespace();
+        }
+
+        if values.is_empty() {
+            return Err(self.unexpected());
+        }
+
+        Ok(SgfProperty { identifier, values })
+    }
+
+    fn parse_value(&mut self) -> Result<String, SgfError> {
+        self.expect('[')?;
+
+        let mut value = String::new();
+        loop {
+            match self.next().ok_or(SgfError::UnexpectedEndOfInput)? {
+                ']' => return Ok(value),
+                '\\' => match self.next().ok_or(SgfError::UnexpectedEndOfInput)? {
+                    // An escaped line break is a soft line break and
+                    // disappears from the value.
+                    '\n' => {
+                        if self.peek() == Some('\r') {
+                            self.position += 1;
+                        }
+                    }
+                    '\r' => {
+                        if self.peek() == Some('\n') {
+                            self.position += 1;
+                        }
+                    }
+                    escaped => value.push(escaped),
+                },
+                character => value.push(character),
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), SgfError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(character) if character == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn unexpected(&self) -> SgfError {
+        match self.peek() {
+            Some(character) => SgfError::UnexpectedCharacter {
+                position: self.position,
+                character,
+            },
+            None => SgfError::UnexpectedEndOfInput,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|character| character.is_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.characters.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let character = self.peek();
+        self.position += 1;
+        character
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property(identifier: &str, values: &[&str]) -> SgfProperty {
+        SgfProperty {
+            identifier: String::from(identifier),
+            values: values.iter().map(|value| String::from(*value)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_main_line() {
+        let game_trees = SgfParser::parse("(;FF[4]SZ[9] ;B[ee]\n;W[])").unwrap();
+
+        let expected_root = SgfNode {
+            properties: vec![property("FF", &["4"]), property("SZ", &["9"])],
+            children: vec![SgfNode {
+                properties: vec![property("B", &["ee"])],
+                children: vec![SgfNode {
+                    properties: vec![property("W", &[""])],
+                    children: vec![],
+                }],
+            }],
+        };
+
+        assert_eq!(vec![expected_root], game_trees);
+    }
+
+    #[test]
+    fn test_parse_variations() {
+        let game_trees = SgfParser::parse("(;SZ[9](;B[aa];W[bb])(;B[cc]))").unwrap();
+
+        let root = &game_trees[0];
+        assert_eq!(2, root.children.len());
+        assert_eq!(Some("aa"), root.children[0].get_value("B"));
+        assert_eq!(Some("bb"), root.children[0].children[0].get_value("W"));
+        assert_eq!(Some("cc"), root.children[1].get_value("B"));
+        assert!(root.children[1].children.is_empty());
+    }
+
+    #[test]
+    fn test_parse_lists_and_escapes() {
+        let game_trees =
+            SgfParser::parse("(;AB[aa][bb:cc]C[a \\] b \\\\ c\\\nd]AddWhite[dd])").unwrap();
+
+        let root = &game_trees[0];
+        assert_eq!(Some(&[String::from("aa"), String::from("bb:cc")][..]), root.get("AB"));
+        assert_eq!(Some("a ] b \\ cd"), root.get_value("C"));
+        assert_eq!(Some("dd"), root.get_value("AW"));
+    }
+
+    #[test]
+    fn test_parse_collection() {
+        let game_trees = SgfParser::parse("(;GM[1])\n(;GM[1])").unwrap();
+
+        assert_eq!(2, game_trees.len());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Err(SgfError::NoGameTree), SgfParser::parse("  "));
+        assert_eq!(Err(SgfError::UnexpectedEndOfInput), SgfParser::parse("(;B[aa]"));
+        assert_eq!(Err(SgfError::UnexpectedEndOfInput), SgfParser::parse("(;C[unterminated"));
+        assert_eq!(
+            Err(SgfError::UnexpectedCharacter { position: 1, character: ')' }),
+            SgfParser::parse("()")
+        );
+        assert_eq!(
+            Err(SgfError::UnexpectedCharacter { position: 4, character: ';' }),
+            SgfParser::parse("(;B ;W[aa])")
+        );
+    }
+}
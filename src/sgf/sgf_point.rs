@@ -0,0 +1,75 @@
+const SGF_LETTERS: &[u8; 52] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub const MAX_SGF_BOARD_SIZE: u32 = SGF_LETTERS.len() as u32;
+
+/// SGF writes a point as its column letter followed by its row letter,
+/// counting from the top left corner with `a`-`z` then `A`-`Z`.
+pub fn encode_point(row: u32, col: u32) -> Option<String> {
+    let col_letter = *SGF_LETTERS.get(col as usize)?;
+    let row_letter = *SGF_LETTERS.get(row as usize)?;
+
+    Some(String::from_utf8(vec![col_letter, row_letter]).unwrap())
+}
+
+pub fn decode_point(value: &str) -> Option<(u32, u32)> {
+    let letters = value.as_bytes();
+    if letters.len() != 2 {
+        return None;
+    }
+
+    let col = SGF_LETTERS.iter().position(|letter| *letter == letters[0])?;
+    let row = SGF_LETTERS.iter().position(|letter| *letter == letters[1])?;
+
+    Some((row as u32, col as u32))
+}
+
+/// Decodes a single point or a compressed `aa:cc` rectangle of points.
+pub fn decode_point_list(value: &str) -> Option<Vec<(u32, u32)>> {
+    match value.split_once(':') {
+        Some((top_left, bottom_right)) => {
+            let (top, left) = decode_point(top_left)?;
+            let (bottom, right) = decode_point(bottom_right)?;
+
+            Some(
+                (top.min(bottom)..=top.max(bottom))
+                    .flat_map(|row| (left.min(right)..=left.max(right)).map(move |col| (row, col)))
+                    .collect(),
+            )
+        }
+        None => Some(vec![decode_point(value)?]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_point() {
+        assert_eq!(Some(String::from("aa")), encode_point(0, 0));
+        assert_eq!(Some(String::from("pd")), encode_point(3, 15));
+        assert_eq!(Some(String::from("sA")), encode_point(26, 18));
+        assert_eq!(Some(String::from("ZZ")), encode_point(51, 51));
+        assert_eq!(None, encode_point(52, 0));
+    }
+
+    #[test]
+    fn test_decode_point() {
+        assert_eq!(Some((0, 0)), decode_point("aa"));
+        assert_eq!(Some((3, 15)), decode_point("pd"));
+        assert_eq!(Some((51, 51)), decode_point("ZZ"));
+        assert_eq!(None, decode_point(""));
+        assert_eq!(None, decode_point("a"));
+        assert_eq!(None, decode_point("a1"));
+    }
+
+    #[test]
+    fn test_decode_point_list() {
+        assert_eq!(Some(vec![(3, 15)]), decode_point_list("pd"));
+        assert_eq!(
+            Some(vec![(0, 1), (0, 2), (1, 1), (1, 2)]),
+            decode_point_list("cb:ba")
+        );
+        assert_eq!(None, decode_point_list("aa:"));
+    }
+}
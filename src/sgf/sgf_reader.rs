@@ -0,0 +1,586 @@
+use crate::domain::game_config::Ruleset;
+use crate::domain::game_status::GameStatus;
+use crate::domain::go_board::GoPlayer;
+use crate::domain::go_game::{GoGame, GoMove};
+use crate::domain::handicap::MIN_HANDICAP;
+use crate::domain::setup::Setup;
+use crate::review::game_tree::GameTree;
+use crate::use_cases::pass_turn::pass_turn_input::PassTurnInput;
+use crate::use_cases::pass_turn::pass_turn_requester::PassTurnRequester;
+use crate::use_cases::place_piece::place_piece_input::PlacePieceInput;
+use crate::use_cases::place_piece::place_piece_requester::PlacePieceRequester;
+
+use super::sgf_error::SgfError;
+use super::sgf_node::SgfNode;
+use super::sgf_parser::SgfParser;
+use super::sgf_point::{decode_point, decode_point_list};
+
+pub struct SgfReader;
+
+impl SgfReader {
+    /// Builds a `GoGame` from the main line of the first game tree in the
+    /// SGF, replaying every move through `place_piece` so that illegal
+    /// moves are reported with the path of the node they were found in.
+    pub fn read(sgf: &str) -> Result<GoGame, SgfError> {
+        let game_trees = SgfParser::parse(sgf)?;
+
+        Self::replay_main_line(&game_trees[0])
+    }
+
+    /// Builds a `GameTree` holding every variation of the first game tree in
+    /// the SGF. Setup properties after the root become setup nodes, and
+    /// nodes with neither a move nor setup are folded into their parent.
+    pub fn read_tree(sgf: &str) -> Result<GameTree, SgfError> {
+        let game_trees = SgfParser::parse(sgf)?;
+        let root = &game_trees[0];
+
+        let mut game = Self::new_game(root)?;
+        Self::apply_node(&mut game, root, &[])?;
+
+        let mut tree = GameTree::new(game);
+        Self::add_variations(&mut tree, root, &mut Vec::new())?;
+        tree.go_to(GameTree::ROOT);
+
+        Ok(tree)
+    }
+
+    pub fn replay_main_line(root: &SgfNode) -> Result<GoGame, SgfError> {
+        let mut game = Self::new_game(root)?;
+
+        let mut node = root;
+        let mut path = Vec::new();
+        loop {
+            Self::apply_node(&mut game, node, &path)?;
+
+            match node.children.first() {
+                Some(child) => {
+                    node = child;
+                    path.push(0);
+                }
+                None => return Ok(game),
+            }
+        }
+    }
+
+    /// Creates the game described by the root node's game info properties.
+    pub fn new_game(root: &SgfNode) -> Result<GoGame, SgfError> {
+        let mut game = match root.get_value("SZ") {
+            Some(value) => Self::parse_size(value)
+                .and_then(|(rows, cols)| GoGame::with_dimensions(rows, cols).ok())
+                .ok_or_else(|| Self::invalid_property("SZ", value))?,
+            None => GoGame::new(19),
+        };
+
+        // A known ruleset also sets the rules the moves are replayed under,
+        // with KM taking precedence over its komi.
+        if let Some(ruleset) = root.get_value("RU").and_then(Ruleset::from_sgf_name) {
+            game.set_ruleset(&ruleset);
+        }
+
+        if let Some(value) = root.get_value("KM") {
+            game.komi = value
+                .trim()
+                .parse()
+                .map_err(|_| Self::invalid_property("KM", value))?;
+        }
+
+        // Only the number is checked here; the handicap stones themselves are
+        // the root's AB stones, which `apply_node` records.
+        if let Some(value) = root.get_value("HA") {
+            value
+                .trim()
+                .parse::<u32>()
+                .map_err(|_| Self::invalid_property("HA", value))?;
+        }
+
+        game.game_info.rules = root.get_value("RU").map(String::from);
+        game.game_info.black_name = root.get_value("PB").map(String::from);
+        game.game_info.white_name = root.get_value("PW").map(String::from);
+        game.game_info.result = root.get_value("RE").map(String::from);
+
+        Ok(game)
+    }
+
+    /// Applies a node's setup properties (AB, AW, AE, PL) followed by its
+    /// move, if it has one.
+    pub fn apply_node(game: &mut GoGame, node: &SgfNode, path: &[usize]) -> Result<(), SgfError> {
+        if let Some(mut setup) = Self::read_setup(game, node)? {
+            // HA only appears in the root, where AB holds the handicap
+            // stones and White moves first.
+            let is_handicap = node
+                .get_value("HA")
+                .and_then(|value| value.trim().parse::<u32>().ok())
+                .is_some_and(|stones| stones >= MIN_HANDICAP)
+                && !setup.black_stones.is_empty();
+            if is_handicap {
+                setup.player = setup.player.or(Some(GoPlayer::WHITE));
+            }
+
+            // The points were checked when reading the setup.
+            game.apply_setup(setup).unwrap();
+            if is_handicap {
+                game.handicap_stones = game.board.stones(&GoPlayer::BLACK).points();
+            }
+        }
+
+        for (identifier, player) in [("B", GoPlayer::BLACK), ("W", GoPlayer::WHITE)] {
+            if let Some(value) = node.get_value(identifier) {
+                Self::play_move(game, player, identifier, value, path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads a node's AE, AB, AW and PL properties, or `None` if it has
+    /// none of them.
+    fn read_setup(game: &GoGame, node: &SgfNode) -> Result<Option<Setup>, SgfError> {
+        let mut setup = Setup::default();
+        let mut has_setup = false;
+
+        for identifier in ["AE", "AB", "AW"] {
+            for value in node.get(identifier).unwrap_or_default() {
+                let points = decode_point_list(value)
+                    .filter(|points| points.iter().all(|(row, col)| game.board.check_bounds(*row, *col).is_ok()))
+                    .ok_or_else(|| Self::invalid_property(identifier, value))?;
+
+                match identifier {
+                    "AE" => setup.empty_points.extend(points),
+                    "AB" => setup.black_stones.extend(points),
+                    _ => setup.white_stones.extend(points),
+                }
+                has_setup = true;
+            }
+        }
+
+        if let Some(value) = node.get_value("PL") {
+            setup.player = Some(Self::parse_player(value).ok_or_else(|| Self::invalid_property("PL", value))?);
+            has_setup = true;
+        }
+
+        Ok(has_setup.then_some(setup))
+    }
+
+    fn add_variations(tree: &mut GameTree, node: &SgfNode, path: &mut Vec<usize>) -> Result<(), SgfError> {
+        for (index, child) in node.children.iter().enumerate() {
+            path.push(index);
+
+            let mut moves = Vec::new();
+            for (identifier, player) in [("B", GoPlayer::BLACK), ("W", GoPlayer::WHITE)] {
+                if let Some(value) = child.get_value(identifier) {
+                    moves.push((player, Self::parse_move(tree.game(), identifier, value)?));
+                }
+            }
+
+            // The setup comes before the node's first move.
+            let mut entered = 0;
+            if let Some(setup) = Self::read_setup(tree.game(), child)? {
+                let first_move = (!moves.is_empty()).then(|| moves.remove(0));
+                tree.set_up(setup, first_move).map_err(|error| SgfError::IllegalMove {
+                    path: path.clone(),
+                    error,
+                })?;
+                entered += 1;
+            }
+            for (player, chosen_move) in moves {
+                tree.play(player, chosen_move).map_err(|error| SgfError::IllegalMove {
+                    path: path.clone(),
+                    error,
+                })?;
+                entered += 1;
+            }
+
+            Self::add_variations(tree, child, path)?;
+
+            for _ in 0..entered {
+                tree.to_parent();
+            }
+            path.pop();
+        }
+
+        Ok(())
+    }
+
+    fn play_move(
+        game: &mut GoGame,
+        player: GoPlayer,
+        identifier: &str,
+        value: &str,
+        path: &[usize],
+    ) -> Result<(), SgfError> {
+        // SGF records may carry on after two passes and don't have to
+        // alternate (e.g. handicap stones played as moves), so resume play
+        // and hand the move to the player as steps of their own, the way
+        // PL would.
+        if game.status == GameStatus::Scoring {
+            game.resume_play();
+        }
+        if player != game.current_player {
+            game.apply_setup(Setup {
+                player: Some(player),
+                ..Setup::default()
+            })
+            .unwrap();
+        }
+
+        match Self::parse_move(game, identifier, value)? {
+            GoMove::Place(row, col) => {
+                let output = game.place_piece(PlacePieceInput { row, col, player });
+
+                match output.error {
+                    Some(error) => Err(SgfError::IllegalMove {
+                        path: path.to_vec(),
+                        error,
+                    }),
+                    None => Ok(()),
+                }
+            }
+            GoMove::Pass => {
+                game.pass_turn(PassTurnInput { player });
+                Ok(())
+            }
+        }
+    }
+
+    /// SZ is either a single size or `columns:rows` for rectangular boards.
+    fn parse_size(value: &str) -> Option<(u32, u32)> {
+        match value.split_once(':') {
+            Some((cols, rows)) => Some((rows.trim().parse().ok()?, cols.trim().parse().ok()?)),
+            None => {
+                let size = value.trim().parse().ok()?;
+                Some((size, size))
+            }
+        }
+    }
+
+    fn parse_move(game: &GoGame, identifier: &str, value: &str) -> Result<GoMove, SgfError> {
+        let is_small_board = game.board.rows() <= 19 && game.board.cols() <= 19;
+        match value {
+            "" => Ok(GoMove::Pass),
+            "tt" if is_small_board => Ok(GoMove::Pass),
+            _ => decode_point(value)
+                .map(|(row, col)| GoMove::Place(row, col))
+                .ok_or_else(|| Self::invalid_property(identifier, value)),
+        }
+    }
+
+    fn parse_player(value: &str) -> Option<GoPlayer> {
+        match value.trim() {
+            "B" | "b" => Some(GoPlayer::BLACK),
+            "W" | "w" => Some(GoPlayer::WHITE),
+            _ => None,
+        }
+    }
+
+    fn invalid_property(identifier: &str, value: &str) -> SgfError {
+        SgfError::InvalidProperty {
+            identifier: String::from(identifier),
+            value: String::from(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::go_game::KoRule;
+    use crate::domain::handicap::HandicapPlacement;
+    use crate::sgf::sgf_writer::SgfWriter;
+    use crate::use_cases::place_piece::place_piece_error::PlacePieceError;
+
+    use super::*;
+
+    #[test]
+    fn test_read_game_info() {
+        let game = SgfReader::read(
+            "(;FF[4]GM[1]SZ[13]KM[6.5]RU[Japanese]PB[Black \\] player]PW[White]RE[W+R])",
+        )
+        .unwrap();
+
+        assert_eq!(13, game.board.board_state().len());
+        assert_eq!(6.5, game.komi);
+        assert_eq!(Some(String::from("Japanese")), game.game_info.rules);
+        assert_eq!(Some(String::from("Black ] player")), game.game_info.black_name);
+        assert_eq!(Some(String::from("White")), game.game_info.white_name);
+        assert_eq!(Some(String::from("W+R")), game.game_info.result);
+        assert_eq!(GoPlayer::BLACK, game.current_player);
+    }
+
+    #[test]
+    fn test_read_replays_main_line() {
+        let game = SgfReader::read("(;SZ[5];B[ba];W[aa];B[ab](;W[tt];B[])(;W[cc]))").unwrap();
+
+        let mut expected_board = GoGame::new(5).board;
+        expected_board.place(0, 1, &GoPlayer::BLACK);
+        expected_board.place(1, 0, &GoPlayer::BLACK);
+
+        assert_eq!(expected_board, game.board);
+        assert_eq!(1, game.prisoners[&GoPlayer::BLACK]);
+        assert_eq!(
+            vec![
+                (GoPlayer::BLACK, GoMove::Place(0, 1)),
+                (GoPlayer::WHITE, GoMove::Place(0, 0)),
+                (GoPlayer::BLACK, GoMove::Place(1, 0)),
+                (GoPlayer::WHITE, GoMove::Pass),
+                (GoPlayer::BLACK, GoMove::Pass)
+            ],
+            game.moves()
+        );
+        assert_eq!(GameStatus::Scoring, game.status);
+    }
+
+    #[test]
+    fn test_read_play_after_passes_and_out_of_turn() {
+        let game = SgfReader::read("(;SZ[5];B[];W[];B[cc];B[dd])").unwrap();
+
+        //Play resumed after the passes and Black was handed the next move
+        assert_eq!(Some(2), game.resumed_at);
+        assert_eq!(GameStatus::InProgress, game.status);
+        assert_eq!(1, game.setup_history.len());
+        assert_eq!(3, game.setup_history[0].move_number);
+        assert_eq!(GoPlayer::WHITE, game.setup_history[0].player_before);
+        assert_eq!(GameStatus::InProgress, game.move_history[2].status_before);
+        assert_eq!(GoPlayer::WHITE, game.current_player);
+
+        let sgf = "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[5]KM[0];B[];W[];B[cc];B[dd])";
+        assert_eq!(Ok(String::from(sgf)), SgfWriter::write(&SgfReader::read(sgf).unwrap()));
+    }
+
+    #[test]
+    fn test_read_setup_stones() {
+        let game = SgfReader::read("(;SZ[9]AB[aa:bb][ee]AW[ia];AE[aa]PL[W];W[ff])").unwrap();
+
+        let mut expected_board = GoGame::new(9).board;
+        expected_board.place(0, 1, &GoPlayer::BLACK);
+        expected_board.place(1, 0, &GoPlayer::BLACK);
+        expected_board.place(1, 1, &GoPlayer::BLACK);
+        expected_board.place(4, 4, &GoPlayer::BLACK);
+        expected_board.place(0, 8, &GoPlayer::WHITE);
+        expected_board.place(5, 5, &GoPlayer::WHITE);
+
+        assert_eq!(expected_board, game.board);
+        assert_eq!(GoPlayer::BLACK, game.current_player);
+    }
+
+    #[test]
+    fn test_read_ruleset() {
+        let game = SgfReader::read("(;SZ[9]RU[NZ])").unwrap();
+
+        assert_eq!(Ruleset::new_zealand().komi, game.komi);
+        assert!(game.suicide_allowed);
+        assert_eq!(Some(String::from("NZ")), game.game_info.rules);
+
+        //Suicide is only legal because of the ruleset
+        let game = SgfReader::read("(;SZ[3]KM[0]RU[NZ];B[ba];W[bb];B[ab];W[aa])").unwrap();
+        assert_eq!(0.0, game.komi);
+        assert_eq!(None, game.board.board_state()[0][0]);
+        assert!(SgfReader::read("(;SZ[3]KM[0]RU[Japanese];B[ba];W[bb];B[ab];W[aa])").is_err());
+
+        let game = SgfReader::read("(;SZ[9]KM[5.5]RU[Pandanet])").unwrap();
+        assert_eq!(KoRule::Simple, game.ko_rule);
+        assert_eq!(Some(String::from("Pandanet")), game.game_info.rules);
+    }
+
+    #[test]
+    fn test_read_handicap_game() {
+        let game = SgfReader::read("(;SZ[9]KM[0.5]HA[2]AB[gc][cg])").unwrap();
+
+        assert_eq!(vec![(2, 6), (6, 2)], game.handicap_stones);
+        assert_eq!(GoPlayer::WHITE, game.current_player);
+
+        //No handicap stones without a handicap
+        let game = SgfReader::read("(;SZ[9]HA[0]AB[gc][cg])").unwrap();
+        assert!(game.handicap_stones.is_empty());
+        assert_eq!(GoPlayer::BLACK, game.current_player);
+
+        assert_eq!(
+            Err(SgfError::InvalidProperty { identifier: String::from("HA"), value: String::from("two") }),
+            SgfReader::read("(;SZ[9]HA[two]AB[gc][cg])").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_handicap_round_trip() {
+        let mut game = GoGame::new(13);
+        game.place_handicap(HandicapPlacement::Fixed { stones: 5 }).unwrap();
+        game.place_piece(PlacePieceInput { row: 2, col: 2, player: GoPlayer::WHITE });
+        game.place_piece(PlacePieceInput { row: 2, col: 3, player: GoPlayer::BLACK });
+
+        let read_game = SgfReader::read(&SgfWriter::write(&game).unwrap()).unwrap();
+
+        assert_eq!(game.handicap_stones, read_game.handicap_stones);
+        assert_eq!(game.komi, read_game.komi);
+        assert_eq!(game.moves(), read_game.moves());
+        assert_eq!(game.board, read_game.board);
+    }
+
+    #[test]
+    fn test_read_reports_illegal_move_path() {
+        let result = SgfReader::read("(;SZ[9];B[aa];W[bb];B[aa])").map(|_| ());
+
+        assert_eq!(
+            Err(SgfError::IllegalMove {
+                path: vec![0, 0, 0],
+                error: PlacePieceError::Occupied {
+                    player: GoPlayer::BLACK,
+                    occupant: GoPlayer::BLACK,
+                    row: 0,
+                    col: 0
+                }
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn test_read_invalid_properties() {
+        assert_eq!(
+            Err(SgfError::InvalidProperty { identifier: String::from("SZ"), value: String::from("53") }),
+            SgfReader::read("(;SZ[53])").map(|_| ())
+        );
+        assert_eq!(
+            Err(SgfError::InvalidProperty { identifier: String::from("SZ"), value: String::from("0:9") }),
+            SgfReader::read("(;SZ[0:9])").map(|_| ())
+        );
+        assert_eq!(
+            Err(SgfError::InvalidProperty { identifier: String::from("KM"), value: String::from("six") }),
+            SgfReader::read("(;KM[six])").map(|_| ())
+        );
+        assert_eq!(
+            Err(SgfError::InvalidProperty { identifier: String::from("B"), value: String::from("e5") }),
+            SgfReader::read("(;SZ[9];B[e5])").map(|_| ())
+        );
+        assert_eq!(
+            Err(SgfError::IllegalMove { path: vec![0], error: PlacePieceError::OutOfBounds { row: 25, col: 25 } }),
+            SgfReader::read("(;SZ[9];B[zz])").map(|_| ())
+        );
+        assert_eq!(
+            Err(SgfError::InvalidProperty { identifier: String::from("AB"), value: String::from("aa:jj") }),
+            SgfReader::read("(;SZ[9]AB[aa:jj])").map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_round_trip_with_writer() {
+        let sgf = "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[9]KM[7.5]RU[Chinese]\
+            PB[Black]PW[White]RE[B+0.5];B[ee];W[ec];B[];W[cc])";
+
+        let game = SgfReader::read(sgf).unwrap();
+
+        assert_eq!(Ok(String::from(sgf)), SgfWriter::write(&game));
+    }
+
+    #[test]
+    fn test_setup_round_trip_with_writer() {
+        let sgf = "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[9]KM[0]AB[aa][bb]AW[cc];W[dd];AE[aa]PL[W];W[ee])";
+
+        let game = SgfReader::read(sgf).unwrap();
+        assert_eq!(Ok(String::from(sgf)), SgfWriter::write(&game));
+
+        let tree = SgfReader::read_tree(sgf).unwrap();
+        assert_eq!(Ok(String::from(sgf)), SgfWriter::write_tree(&tree));
+
+        let sgf = "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[9]KM[0]AB[aa][bb](;W[cc])(;AB[dd]W[ee]))";
+        let tree = SgfReader::read_tree(sgf).unwrap();
+        assert_eq!(Ok(String::from(sgf)), SgfWriter::write_tree(&tree));
+    }
+
+    #[test]
+    fn test_read_rectangular_board() {
+        let sgf = "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[13:5]KM[0];B[me];W[ma];B[tt])";
+
+        let game = SgfReader::read(sgf).unwrap();
+
+        assert_eq!(5, game.board.rows());
+        assert_eq!(13, game.board.cols());
+        assert_eq!(Some(GoPlayer::BLACK), game.board.board_state()[4][12]);
+        assert_eq!(Some(GoPlayer::WHITE), game.board.board_state()[0][12]);
+        assert_eq!((GoPlayer::BLACK, GoMove::Pass), game.moves()[2]);
+
+        assert_eq!(Ok(sgf.replace("B[tt]", "B[]")), SgfWriter::write(&game));
+    }
+
+    #[test]
+    fn test_read_tree_with_variations() {
+        let tree = SgfReader::read_tree("(;SZ[9];B[cc](;W[gg];;B[cg])(;W[])(;W[cc]))");
+        assert_eq!(
+            Err(SgfError::IllegalMove {
+                path: vec![0, 2],
+                error: PlacePieceError::Occupied {
+                    player: GoPlayer::WHITE,
+                    occupant: GoPlayer::BLACK,
+                    row: 2,
+                    col: 2
+                }
+            }),
+            tree.map(|_| ())
+        );
+
+        let mut tree = SgfReader::read_tree("(;SZ[9];B[cc](;W[gg];;B[cg])(;W[]))").unwrap();
+
+        assert_eq!(GameTree::ROOT, tree.current());
+        assert_eq!(4, tree.main_line().len());
+        assert!(tree.to_child(0));
+        assert_eq!(2, tree.node(tree.current()).unwrap().children.len());
+        assert!(tree.to_child(1));
+        assert_eq!(Some((GoPlayer::WHITE, GoMove::Pass)), tree.node(tree.current()).unwrap().chosen_move);
+    }
+
+    #[test]
+    fn test_read_tree_with_setup_nodes() {
+        let mut tree = SgfReader::read_tree("(;SZ[9]AB[aa];B[cc](;AE[aa]AW[bb]PL[W];W[dd])(;W[ee];AB[ff]W[gg]))").unwrap();
+
+        let main_line = tree.main_line();
+        assert_eq!(4, main_line.len());
+        let setup = Setup {
+            white_stones: vec![(1, 1)],
+            empty_points: vec![(0, 0)],
+            player: Some(GoPlayer::WHITE),
+            ..Setup::default()
+        };
+        assert_eq!(Some(&setup), tree.node(main_line[2]).unwrap().setup.as_ref());
+        assert_eq!(None, tree.node(main_line[2]).unwrap().chosen_move);
+
+        assert!(tree.go_to(main_line[3]));
+        assert_eq!(None, tree.game().board.board_state()[0][0]);
+        assert_eq!(Some(GoPlayer::WHITE), tree.game().board.board_state()[1][1]);
+        assert_eq!(Some(GoPlayer::WHITE), tree.game().board.board_state()[3][3]);
+
+        //Going back takes the setup back too
+        assert!(tree.to_parent());
+        assert!(tree.to_parent());
+        assert_eq!(Some(GoPlayer::BLACK), tree.game().board.board_state()[0][0]);
+        assert_eq!(None, tree.game().board.board_state()[1][1]);
+        assert_eq!(GoPlayer::WHITE, tree.game().current_player);
+
+        //The setup and the move of a node are applied in that order
+        assert!(tree.to_child(1));
+        assert!(tree.to_child(0));
+        let node = tree.node(tree.current()).unwrap();
+        assert_eq!(Some((GoPlayer::WHITE, GoMove::Place(6, 6))), node.chosen_move);
+        assert_eq!(vec![(5, 5)], node.setup.as_ref().unwrap().black_stones);
+        assert_eq!(Some(GoPlayer::BLACK), tree.game().board.board_state()[5][5]);
+        assert_eq!(Some(GoPlayer::WHITE), tree.game().board.board_state()[6][6]);
+    }
+
+    #[test]
+    fn test_tree_round_trip_with_writer() {
+        let sgf = "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[9]KM[6.5]PB[Black]\
+            ;B[cc](;W[gg](;B[cg];W[gc])(;B[gc]))(;W[];B[ee]))";
+
+        let mut tree = SgfReader::read_tree(sgf).unwrap();
+
+        assert_eq!(Ok(String::from(sgf)), SgfWriter::write_tree(&tree));
+
+        tree.go_to(tree.main_line()[2]);
+        assert!(tree.to_child(1));
+        tree.promote_to_main_line();
+
+        assert_eq!(
+            Ok(String::from(
+                "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[9]KM[6.5]PB[Black]\
+                ;B[cc](;W[gg](;B[gc])(;B[cg];W[gc]))(;W[];B[ee]))"
+            )),
+            SgfWriter::write_tree(&tree)
+        );
+    }
+}
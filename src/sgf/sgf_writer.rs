@@ -0,0 +1,328 @@
+use crate::domain::go_board::GoPlayer;
+use crate::domain::go_game::{GoGame, GoMove};
+use crate::domain::setup::Setup;
+use crate::review::game_tree::{GameTree, NodeId};
+
+use super::sgf_error::SgfError;
+use super::sgf_point::{encode_point, MAX_SGF_BOARD_SIZE};
+
+pub struct SgfWriter;
+
+impl SgfWriter {
+    /// Serializes the game as a single-line SGF FF[4] record: the root node
+    /// holds the game info and the setup made before any move, and every
+    /// later setup and every move in `move_history` (passes included)
+    /// follows as its own node.
+    pub fn write(game: &GoGame) -> Result<String, SgfError> {
+        let root_setup = game.setup_history.first().filter(|record| record.move_number == 0);
+        let mut sgf = Self::write_root(game, root_setup.map(|record| &record.setup))?;
+
+        let mut setups = game.setup_history.iter().skip(root_setup.iter().count()).peekable();
+        for (move_number, (player, chosen_move)) in game.moves().into_iter().enumerate() {
+            while let Some(record) = setups.next_if(|record| record.move_number == move_number) {
+                // Handing the move to the player who then makes it is what
+                // the move's B or W says anyway.
+                let hands_over_move = record.setup.changes().is_empty()
+                    && record.setup.player == Some(player)
+                    && setups.peek().is_none_or(|next| next.move_number != move_number);
+                if !hands_over_move {
+                    sgf.push(';');
+                    Self::push_setup(&mut sgf, &record.setup, None);
+                }
+            }
+            sgf.push(';');
+            Self::push_move(&mut sgf, player, chosen_move);
+        }
+        for record in setups {
+            sgf.push(';');
+            Self::push_setup(&mut sgf, &record.setup, None);
+        }
+
+        sgf.push(')');
+
+        Ok(sgf)
+    }
+
+    /// Serializes the whole tree: the main line continues node after node
+    /// and every branch point opens one parenthesized game tree per
+    /// variation, main line first.
+    pub fn write_tree(tree: &GameTree) -> Result<String, SgfError> {
+        let root_setup = tree.node(GameTree::ROOT).unwrap().setup.as_ref();
+        let mut sgf = Self::write_root(tree.game(), root_setup)?;
+
+        Self::push_sequence(&mut sgf, tree, GameTree::ROOT);
+
+        sgf.push(')');
+
+        Ok(sgf)
+    }
+
+    fn write_root(game: &GoGame, setup: Option<&Setup>) -> Result<String, SgfError> {
+        let (rows, cols) = (game.board.rows(), game.board.cols());
+        if rows > MAX_SGF_BOARD_SIZE || cols > MAX_SGF_BOARD_SIZE {
+            return Err(SgfError::BoardTooLarge { rows, cols });
+        }
+
+        let mut sgf = String::from("(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]");
+        // Rectangular boards are written as SZ[columns:rows].
+        if rows == cols {
+            sgf.push_str(&format!("SZ[{}]", rows));
+        } else {
+            sgf.push_str(&format!("SZ[{}:{}]", cols, rows));
+        }
+        sgf.push_str(&format!("KM[{}]", game.komi));
+        if !game.handicap_stones.is_empty() {
+            sgf.push_str(&format!("HA[{}]", game.handicap_stones.len()));
+        }
+
+        let game_info = &game.game_info;
+        Self::push_text_property(&mut sgf, "RU", &game_info.rules);
+        Self::push_text_property(&mut sgf, "PB", &game_info.black_name);
+        Self::push_text_property(&mut sgf, "PW", &game_info.white_name);
+        Self::push_text_property(&mut sgf, "RE", &game_info.result);
+
+        if let Some(setup) = setup {
+            // White moving first goes without saying in a handicap game.
+            let implied_player = match game.handicap_stones.is_empty() {
+                true => None,
+                false => Some(GoPlayer::WHITE),
+            };
+            Self::push_setup(&mut sgf, setup, implied_player);
+        }
+
+        Ok(sgf)
+    }
+
+    fn push_sequence(sgf: &mut String, tree: &GameTree, mut node: NodeId) {
+        loop {
+            // Node ids only come from the tree itself.
+            let children = &tree.node(node).unwrap().children;
+            match children.len() {
+                0 => return,
+                1 => {
+                    node = children[0];
+                    Self::push_tree_node(sgf, tree, node);
+                }
+                _ => {
+                    for child in children {
+                        sgf.push('(');
+                        Self::push_tree_node(sgf, tree, *child);
+                        Self::push_sequence(sgf, tree, *child);
+                        sgf.push(')');
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    fn push_tree_node(sgf: &mut String, tree: &GameTree, node: NodeId) {
+        let node = tree.node(node).unwrap();
+
+        sgf.push(';');
+        if let Some(setup) = &node.setup {
+            // The node's move already says whose turn it is.
+            Self::push_setup(sgf, setup, node.chosen_move.map(|(player, _)| player));
+        }
+        if let Some((player, chosen_move)) = node.chosen_move {
+            Self::push_move(sgf, player, chosen_move);
+        }
+    }
+
+    /// Writes the AB, AW and AE properties of a setup and PL unless the
+    /// player is the one implied anyway.
+    fn push_setup(sgf: &mut String, setup: &Setup, implied_player: Option<GoPlayer>) {
+        for (identifier, points) in [
+            ("AB", &setup.black_stones),
+            ("AW", &setup.white_stones),
+            ("AE", &setup.empty_points),
+        ] {
+            if !points.is_empty() {
+                sgf.push_str(identifier);
+                for (row, col) in points.iter() {
+                    sgf.push_str(&format!("[{}]", encode_point(*row, *col).unwrap()));
+                }
+            }
+        }
+
+        if let Some(player) = setup.player.filter(|player| Some(*player) != implied_player) {
+            let colour = match player {
+                GoPlayer::BLACK => "B",
+                GoPlayer::WHITE => "W",
+            };
+            sgf.push_str(&format!("PL[{}]", colour));
+        }
+    }
+
+    fn push_move(sgf: &mut String, player: GoPlayer, chosen_move: GoMove) {
+        let colour = match player {
+            GoPlayer::BLACK => "B",
+            GoPlayer::WHITE => "W",
+        };
+        let point = match chosen_move {
+            GoMove::Place(row, col) => encode_point(row, col).unwrap(),
+            GoMove::Pass => String::new(),
+        };
+
+        sgf.push_str(&format!("{}[{}]", colour, point));
+    }
+
+    fn push_text_property(sgf: &mut String, identifier: &str, value: &Option<String>) {
+        if let Some(value) = value {
+            sgf.push_str(&format!("{}[{}]", identifier, Self::escape_text(value)));
+        }
+    }
+
+    fn escape_text(value: &str) -> String {
+        value.replace('\\', "\\\\").replace(']', "\\]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::handicap::HandicapPlacement;
+    use crate::use_cases::pass_turn::pass_turn_input::PassTurnInput;
+    use crate::use_cases::pass_turn::pass_turn_requester::PassTurnRequester;
+    use crate::use_cases::place_piece::place_piece_input::PlacePieceInput;
+    use crate::use_cases::place_piece::place_piece_requester::PlacePieceRequester;
+
+    use super::*;
+
+    #[test]
+    fn test_write_handicap_game() {
+        let mut game = GoGame::new(19);
+        game.place_handicap(HandicapPlacement::Fixed { stones: 3 }).unwrap();
+        game.place_piece(PlacePieceInput { row: 2, col: 2, player: GoPlayer::WHITE });
+
+        assert_eq!(
+            Ok(String::from(
+                "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[19]KM[0.5]HA[3]AB[pd][dp][pp];W[cc])"
+            )),
+            SgfWriter::write(&game)
+        );
+    }
+
+    #[test]
+    fn test_write_empty_game() {
+        let game = GoGame::new(19);
+
+        assert_eq!(
+            Ok(String::from("(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[19]KM[0])")),
+            SgfWriter::write(&game)
+        );
+    }
+
+    #[test]
+    fn test_write_game_info_and_moves() {
+        let mut game = GoGame::new(9);
+        game.komi = 6.5;
+        game.game_info.rules = Some(String::from("Japanese"));
+        game.game_info.black_name = Some(String::from("Honinbo [Shusaku]"));
+        game.game_info.white_name = Some(String::from("Gennan\\Inseki"));
+        game.game_info.result = Some(String::from("B+2.5"));
+
+        game.place_piece(PlacePieceInput {
+            row: 2,
+            col: 6,
+            player: GoPlayer::BLACK
+        });
+        game.place_piece(PlacePieceInput {
+            row: 6,
+            col: 2,
+            player: GoPlayer::WHITE
+        });
+        game.pass_turn(PassTurnInput {
+            player: GoPlayer::BLACK
+        });
+        game.pass_turn(PassTurnInput {
+            player: GoPlayer::WHITE
+        });
+
+        assert_eq!(
+            Ok(String::from(
+                "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[9]KM[6.5]RU[Japanese]\
+                PB[Honinbo [Shusaku\\]]PW[Gennan\\\\Inseki]RE[B+2.5]\
+                ;B[gc];W[cg];B[];W[])"
+            )),
+            SgfWriter::write(&game)
+        );
+    }
+
+    #[test]
+    fn test_write_rectangular_board() {
+        let mut game = GoGame::with_dimensions(5, 13).unwrap();
+        game.place_piece(PlacePieceInput {
+            row: 4,
+            col: 12,
+            player: GoPlayer::BLACK
+        });
+
+        assert_eq!(
+            Ok(String::from("(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[13:5]KM[0];B[me])")),
+            SgfWriter::write(&game)
+        );
+    }
+
+    #[test]
+    fn test_write_tree_with_variations() {
+        let mut tree = GameTree::new(GoGame::new(9));
+        tree.play(GoPlayer::BLACK, GoMove::Place(2, 2)).unwrap();
+        tree.play(GoPlayer::WHITE, GoMove::Place(6, 6)).unwrap();
+        tree.play(GoPlayer::BLACK, GoMove::Place(6, 2)).unwrap();
+        tree.to_parent();
+        tree.to_parent();
+        tree.play(GoPlayer::WHITE, GoMove::Pass).unwrap();
+
+        assert_eq!(
+            Ok(String::from(
+                "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[9]KM[0]\
+                ;B[cc](;W[gg];B[cg])(;W[]))"
+            )),
+            SgfWriter::write_tree(&tree)
+        );
+    }
+
+    #[test]
+    fn test_write_setup() {
+        let mut game = GoGame::new(9);
+        game.apply_setup(Setup { black_stones: vec![(0, 0), (1, 1)], ..Setup::default() }).unwrap();
+        game.place_piece(PlacePieceInput { row: 2, col: 2, player: GoPlayer::BLACK });
+        game.apply_setup(Setup {
+            white_stones: vec![(3, 3)],
+            empty_points: vec![(0, 0)],
+            player: Some(GoPlayer::BLACK),
+            ..Setup::default()
+        })
+        .unwrap();
+        game.place_piece(PlacePieceInput { row: 4, col: 4, player: GoPlayer::BLACK });
+        game.apply_setup(Setup { player: Some(GoPlayer::BLACK), ..Setup::default() }).unwrap();
+
+        assert_eq!(
+            Ok(String::from(
+                "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[9]KM[0]AB[aa][bb]\
+                ;B[cc];AW[dd]AE[aa]PL[B];B[ee];PL[B])"
+            )),
+            SgfWriter::write(&game)
+        );
+    }
+
+    #[test]
+    fn test_write_tree_from_game_with_moves() {
+        let mut game = GoGame::new(9);
+        game.place_piece(PlacePieceInput { row: 2, col: 2, player: GoPlayer::BLACK });
+        game.place_piece(PlacePieceInput { row: 6, col: 6, player: GoPlayer::WHITE });
+
+        let mut tree = GameTree::new(game);
+        tree.to_parent();
+        tree.set_up(Setup { black_stones: vec![(4, 4)], ..Setup::default() }, Some((GoPlayer::WHITE, GoMove::Pass)))
+            .unwrap();
+
+        assert_eq!(
+            Ok(String::from(
+                "(;FF[4]GM[1]CA[UTF-8]AP[RustGo:0.1.0]SZ[9]KM[0]\
+                ;B[cc](;W[gg])(;AB[ee]W[]))"
+            )),
+            SgfWriter::write_tree(&tree)
+        );
+    }
+}
@@ -1 +1,6 @@
-pub mod place_piece;
\ No newline at end of file
+pub mod place_piece;
+pub mod pass_turn;
+pub mod undo_move;
+pub mod redo_move;
+pub mod resign;
+pub mod scoring_phase;
\ No newline at end of file
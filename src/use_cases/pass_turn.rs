@@ -0,0 +1,5 @@
+pub mod pass_turn_input;
+pub mod pass_turn_output;
+
+pub mod pass_turn_requester;
+pub mod pass_turn_interactor;
\ No newline at end of file
@@ -0,0 +1,5 @@
+use crate::domain::go_board::GoPlayer;
+
+pub struct PassTurnInput {
+    pub player: GoPlayer
+}
@@ -0,0 +1,201 @@
+use crate::domain::game_status::GameStatus;
+use crate::domain::go_game::{GoGame, GoMove};
+use crate::domain::util::dead_stone_estimator::DeadStoneEstimator;
+
+use super::pass_turn_input::PassTurnInput;
+use super::pass_turn_requester::PassTurnRequester;
+use super::pass_turn_output::PassTurnOutput;
+
+impl PassTurnRequester for GoGame {
+    fn pass_turn(&mut self, pass_turn_input: PassTurnInput) -> PassTurnOutput {
+        let cloned_board = self.board.board_state().clone();
+
+        if self.is_over() {
+            return PassTurnOutput {
+                success: false,
+                board_state: Some(cloned_board),
+                next_player: Some(self.current_player),
+                game_over: true,
+                error_msg: Some(String::from("The game is over."))
+            };
+        }
+
+        if pass_turn_input.player != self.current_player {
+            return PassTurnOutput {
+                success: false,
+                board_state: Some(cloned_board),
+                next_player: Some(self.current_player),
+                game_over: false,
+                error_msg: Some(format!("The player {:?} tried to move when it is {:?}'s turn.", 
+                    pass_turn_input.player, self.current_player))
+            };
+        }
+
+        // The checks above cover everything that can stop a pass.
+        self.apply(GoMove::Pass).unwrap();
+        self.undone_moves.clear();
+
+        // Start the scoring phase from a guess at the dead stones, which
+        // the players then only need to correct.
+        if self.status == GameStatus::Scoring {
+            self.dead_stone_marking.dead_stones = DeadStoneEstimator::estimate(&self.board).into_iter().collect();
+        }
+
+        PassTurnOutput {
+            success: true,
+            board_state: Some(cloned_board),
+            next_player: Some(self.current_player),
+            game_over: self.is_over(),
+            error_msg: None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::domain::go_board::{GoPlayer, GoBoard};
+    use crate::use_cases::place_piece::place_piece_input::PlacePieceInput;
+    use crate::use_cases::place_piece::place_piece_error::PlacePieceError;
+    use crate::use_cases::place_piece::place_piece_output::PlacePieceOutput;
+    use crate::use_cases::place_piece::place_piece_requester::PlacePieceRequester;
+
+    use super::*;
+
+    #[test]
+    fn test_black_passes() {
+        let mut game = GoGame::new(9);
+
+        let output = game.pass_turn(PassTurnInput {
+            player: GoPlayer::BLACK
+        });
+
+        let expected_output = PassTurnOutput {
+            success: true,
+            board_state: Some(GoBoard::new(9).board_state().clone()),
+            next_player: Some(GoPlayer::WHITE),
+            game_over: false,
+            error_msg: None
+        };
+
+        assert_eq!(expected_output, output);
+        assert_eq!(vec![(GoPlayer::BLACK, GoMove::Pass)], game.moves());
+    }
+
+    #[test]
+    fn test_pass_wrong_player() {
+        let mut game = GoGame::new(9);
+
+        let output = game.pass_turn(PassTurnInput {
+            player: GoPlayer::WHITE
+        });
+
+        let expected_output = PassTurnOutput {
+            success: false,
+            board_state: Some(GoBoard::new(9).board_state().clone()),
+            next_player: Some(GoPlayer::BLACK),
+            game_over: false,
+            error_msg: Some(String::from("The player WHITE tried to move when it is BLACK's turn."))
+        };
+
+        assert_eq!(expected_output, output);
+        assert!(game.move_history.is_empty());
+    }
+
+    #[test]
+    fn test_two_consecutive_passes_end_game() {
+        let mut game = GoGame::new(9);
+
+        game.pass_turn(PassTurnInput {
+            player: GoPlayer::BLACK
+        });
+        let output = game.pass_turn(PassTurnInput {
+            player: GoPlayer::WHITE
+        });
+
+        let expected_output = PassTurnOutput {
+            success: true,
+            board_state: Some(GoBoard::new(9).board_state().clone()),
+            next_player: Some(GoPlayer::BLACK),
+            game_over: true,
+            error_msg: None
+        };
+
+        assert_eq!(expected_output, output);
+        assert_eq!(GameStatus::Scoring, game.status);
+
+        let output = game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 0,
+            player: GoPlayer::BLACK
+        });
+
+        let expected_output = PlacePieceOutput {
+            success: false,
+            board_state: Some(GoBoard::new(9).board_state().clone()),
+            next_player: Some(GoPlayer::BLACK),
+            captured_stones: vec![],
+            stones_captured: 0,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]),
+            error: Some(PlacePieceError::GameOver)
+        };
+
+        assert_eq!(expected_output, output);
+    }
+
+    #[test]
+    fn test_pass_after_move_does_not_end_game() {
+        let mut game = GoGame::new(9);
+
+        game.pass_turn(PassTurnInput {
+            player: GoPlayer::BLACK
+        });
+        game.place_piece(PlacePieceInput {
+            row: 4,
+            col: 4,
+            player: GoPlayer::WHITE
+        });
+        let output = game.pass_turn(PassTurnInput {
+            player: GoPlayer::BLACK
+        });
+
+        assert!(output.success);
+        assert!(!output.game_over);
+        assert!(!game.is_over());
+        assert_eq!(
+            vec![
+                (GoPlayer::BLACK, GoMove::Pass),
+                (GoPlayer::WHITE, GoMove::Place(4, 4)),
+                (GoPlayer::BLACK, GoMove::Pass)
+            ],
+            game.moves()
+        );
+    }
+
+    #[test]
+    fn test_second_pass_estimates_dead_stones() {
+        let mut game = GoGame::new(5);
+        game.board = GoBoard::from_rows(&[
+            "OX.O.",
+            ".X.O.",
+            "XX.OX",
+            ".X.O.",
+            ".X.O."
+        ]);
+
+        game.pass_turn(PassTurnInput {
+            player: GoPlayer::BLACK
+        });
+        assert!(game.dead_stone_marking.dead_stones.is_empty());
+        game.pass_turn(PassTurnInput {
+            player: GoPlayer::WHITE
+        });
+
+        assert_eq!(vec![(0, 0), (2, 4)], game.dead_stone_marking.sorted_dead_stones());
+
+        game.unapply();
+        assert!(game.dead_stone_marking.dead_stones.is_empty());
+    }
+}
@@ -0,0 +1,10 @@
+use crate::domain::go_board::{GoBoardState, GoPlayer};
+
+#[derive(PartialEq, Debug)]
+pub struct PassTurnOutput {
+    pub success: bool,
+    pub board_state: Option<GoBoardState>,
+    pub next_player: Option<GoPlayer>,
+    pub game_over: bool,
+    pub error_msg: Option<String>
+}
@@ -0,0 +1,5 @@
+use super::{pass_turn_output::PassTurnOutput, pass_turn_input::PassTurnInput};
+
+pub trait PassTurnRequester {
+    fn pass_turn(&mut self, pass_turn_input: PassTurnInput) -> PassTurnOutput;
+}
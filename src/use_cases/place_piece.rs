@@ -1,5 +1,6 @@
 pub mod place_piece_input;
 pub mod place_piece_output;
+pub mod place_piece_error;
 
 pub mod place_piece_requester;
 pub mod place_piece_interactor;
\ No newline at end of file
@@ -0,0 +1,99 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::domain::go_board::{GoPlayer, OutOfBoundsError};
+use crate::domain::go_game::PlacementError;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PlacePieceError {
+    GameOver,
+    OutOfBounds { row: u32, col: u32 },
+    WrongTurn { player: GoPlayer, current_player: GoPlayer },
+    Occupied { player: GoPlayer, occupant: GoPlayer, row: u32, col: u32 },
+    Ko { player: GoPlayer, row: u32, col: u32 },
+    Suicide { player: GoPlayer, row: u32, col: u32 }
+}
+
+impl fmt::Display for PlacePieceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlacePieceError::GameOver => write!(f, "The game is over."),
+            PlacePieceError::OutOfBounds { row, col } => write!(f,
+                "{}", OutOfBoundsError { row: *row, col: *col }),
+            PlacePieceError::WrongTurn { player, current_player } => write!(f,
+                "The player {:?} tried to move when it is {:?}'s turn.", player, current_player),
+            PlacePieceError::Occupied { player, occupant, .. } => write!(f,
+                "The player {:?} tried to place a piece on a square occupied by {:?}.", player, occupant),
+            PlacePieceError::Ko { player, .. } => write!(f,
+                "The player {:?} has violated the Ko rule.", player),
+            PlacePieceError::Suicide { player, .. } => write!(f,
+                "The player {:?} tried to commit suicide.", player)
+        }
+    }
+}
+
+impl Error for PlacePieceError {}
+
+impl PlacePieceError {
+    pub fn from_placement_error(error: PlacementError, player: GoPlayer, row: u32, col: u32) -> Self {
+        match error {
+            PlacementError::GameOver => PlacePieceError::GameOver,
+            PlacementError::OutOfBounds => PlacePieceError::OutOfBounds { row, col },
+            PlacementError::Occupied { occupant } => PlacePieceError::Occupied { player, occupant, row, col },
+            PlacementError::Ko => PlacePieceError::Ko { player, row, col },
+            PlacementError::Suicide => PlacePieceError::Suicide { player, row, col }
+        }
+    }
+}
+
+impl From<OutOfBoundsError> for PlacePieceError {
+    fn from(error: OutOfBoundsError) -> Self {
+        PlacePieceError::OutOfBounds { row: error.row, col: error.col }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!("The game is over.", PlacePieceError::GameOver.to_string());
+        assert_eq!(
+            "The point (19, 3) is outside the board.",
+            PlacePieceError::OutOfBounds { row: 19, col: 3 }.to_string()
+        );
+        assert_eq!(
+            "The player WHITE tried to move when it is BLACK's turn.",
+            PlacePieceError::WrongTurn {
+                player: GoPlayer::WHITE,
+                current_player: GoPlayer::BLACK
+            }.to_string()
+        );
+        assert_eq!(
+            "The player WHITE tried to place a piece on a square occupied by BLACK.",
+            PlacePieceError::Occupied {
+                player: GoPlayer::WHITE,
+                occupant: GoPlayer::BLACK,
+                row: 0,
+                col: 0
+            }.to_string()
+        );
+        assert_eq!(
+            "The player WHITE has violated the Ko rule.",
+            PlacePieceError::Ko {
+                player: GoPlayer::WHITE,
+                row: 1,
+                col: 2
+            }.to_string()
+        );
+        assert_eq!(
+            "The player BLACK tried to commit suicide.",
+            PlacePieceError::Suicide {
+                player: GoPlayer::BLACK,
+                row: 0,
+                col: 0
+            }.to_string()
+        );
+    }
+}
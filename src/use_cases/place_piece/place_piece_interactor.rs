@@ -1,100 +1,86 @@
-use crate::domain::go_board::{GoPlayer};
-use crate::domain::go_game::{GoGame, Snapshot};
-use crate::domain::util::group_liberties_aggregator::GroupLibertiesAggregator;
+use crate::domain::go_game::{GoGame, GoMove};
 
 use super::place_piece_input::PlacePieceInput;
 use super::place_piece_requester::PlacePieceRequester;
 use super::place_piece_output::PlacePieceOutput;
+use super::place_piece_error::PlacePieceError;
 
 impl PlacePieceRequester for GoGame {
     fn place_piece(&mut self, place_piece_input: PlacePieceInput) -> PlacePieceOutput {
-        let cloned_board_before_change = self.board.board_state.clone();
+        if self.is_over() {
+            return PlacePieceOutput {
+                success: false,
+                board_state: Some(self.board.board_state().clone()),
+                next_player: Some(self.current_player),
+                captured_stones: Vec::new(),
+                stones_captured: 0,
+                suicided_stones: Vec::new(),
+                prisoners: self.prisoners.clone(),
+                error: Some(PlacePieceError::GameOver)
+            };
+        }
 
         if place_piece_input.player != self.current_player {
             return PlacePieceOutput {
                 success: false,
-                board_state: Some(cloned_board_before_change),
+                board_state: Some(self.board.board_state().clone()),
                 next_player: Some(self.current_player),
-                error_msg: Some(format!("The player {:?} tried to move when it is {:?}'s turn.", 
-                    place_piece_input.player, self.current_player))
+                captured_stones: Vec::new(),
+                stones_captured: 0,
+                suicided_stones: Vec::new(),
+                prisoners: self.prisoners.clone(),
+                error: Some(PlacePieceError::WrongTurn {
+                    player: place_piece_input.player,
+                    current_player: self.current_player
+                })
             };
         }
 
         let row = place_piece_input.row;
         let col = place_piece_input.col;
 
-        let state_before_last = self.two_previous_states[1].as_ref();
-
-        if self.has_violated_ko_rule(state_before_last, row, col) {
+        if let Err(error) = self.apply(GoMove::Place(row, col)) {
             return PlacePieceOutput {
                 success: false,
-                board_state: Some(cloned_board_before_change),
+                board_state: Some(self.board.board_state().clone()),
                 next_player: Some(self.current_player),
-                error_msg: Some(format!("The player {:?} has violated the Ko rule.", self.current_player))
-            }
+                captured_stones: Vec::new(),
+                stones_captured: 0,
+                suicided_stones: Vec::new(),
+                prisoners: self.prisoners.clone(),
+                error: Some(PlacePieceError::from_placement_error(error, place_piece_input.player, row, col))
+            };
         }
+        self.undone_moves.clear();
 
-        let result = self.board.place(row, col, &place_piece_input.player);
+        let record = self.move_history.last().unwrap();
+        let mut captured_stones = record.captured_stones.clone();
+        captured_stones.sort();
+        let mut suicided_stones = record.suicided_stones.clone();
+        suicided_stones.sort();
 
-        match result {
-            true => {
-                match self.current_player {
-                    GoPlayer::BLACK => self.current_player = GoPlayer::WHITE,
-                    GoPlayer::WHITE => self.current_player = GoPlayer::BLACK
-                }
-
-                let group_liberties = GroupLibertiesAggregator::get_group_liberties(&self.board);
-                let enemy_groups = group_liberties.get(&self.current_player).unwrap();
-
-                enemy_groups.iter().for_each(|group| {
-                    if group.1.len() == 0 {
-                        for square in group.0.iter() {
-                            self.board.remove(square.0, square.1)
-                        }
-                    }
-                });
-
-                self.two_previous_states.rotate_right(1);
-                self.two_previous_states[0] = Some(Snapshot {
-                    state: cloned_board_before_change,
-                    chosen_move: (row, col),
-                });
-
-                PlacePieceOutput {
-                    success: true,
-                    board_state: Some(self.board.board_state.clone()),
-                    next_player: Some(self.current_player),
-                    error_msg: None
-                }
-            },
-            false => {
-                PlacePieceOutput {
-                    success: false,
-                    board_state: Some(cloned_board_before_change),
-                    next_player: Some(self.current_player),
-                    error_msg: Some(format!(
-                        "The player {:?} tried to place a piece on a square occupied by {:?}.",
-                        place_piece_input.player, 
-                        self.board.board_state[row as usize]
-                            [col as usize].unwrap()
-                    ))
-                }
-            }
+        PlacePieceOutput {
+            success: true,
+            board_state: Some(self.board.board_state().clone()),
+            next_player: Some(self.current_player),
+            stones_captured: captured_stones.len() as u32,
+            captured_stones,
+            suicided_stones,
+            prisoners: self.prisoners.clone(),
+            error: None
         }
     }
 }
 
-impl GoGame {
-    fn has_violated_ko_rule(&self, state_before_last: Option<&Snapshot>, row: u32, col: u32) -> bool {
-        state_before_last.is_some() && 
-        self.board.board_state == state_before_last.unwrap().state && 
-        (row, col) == state_before_last.unwrap().chosen_move
-    }
-}
-
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
+    use proptest::prelude::*;
+
     use crate::domain::go_board::{GoPlayer, GoBoard};
+    use crate::domain::go_game::KoRule;
+    use crate::domain::util::group_liberties_aggregator::GroupLibertiesAggregator;
 
     use super::*;
 
@@ -109,14 +95,18 @@ mod tests {
 
         let output = game.place_piece(input);
 
-        let mut expected_board_state = GoBoard::new(19).board_state;
+        let mut expected_board_state = GoBoard::new(19).board_state().clone();
         expected_board_state[0][0] = Some(GoPlayer::BLACK);
 
         let expected_output = PlacePieceOutput {
             success: true,
             board_state: Some(expected_board_state),
             next_player: Some(GoPlayer::WHITE),
-            error_msg: None
+            captured_stones: vec![],
+            stones_captured: 0,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]),
+            error: None
         };
 
         assert_eq!(expected_output, output);
@@ -133,13 +123,20 @@ mod tests {
 
         let output = game.place_piece(input);
 
-        let expected_board_state = GoBoard::new(19).board_state;
+        let expected_board_state = GoBoard::new(19).board_state().clone();
 
         let expected_output = PlacePieceOutput {
             success: false,
             board_state: Some(expected_board_state),
             next_player: Some(GoPlayer::BLACK),
-            error_msg: Some(String::from("The player WHITE tried to move when it is BLACK's turn."))
+            captured_stones: vec![],
+            stones_captured: 0,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]),
+            error: Some(PlacePieceError::WrongTurn {
+                player: GoPlayer::WHITE,
+                current_player: GoPlayer::BLACK
+            })
         };
 
         assert_eq!(expected_output, output);
@@ -156,14 +153,18 @@ mod tests {
 
         let output = game.place_piece(input);
 
-        let mut expected_board_state = GoBoard::new(19).board_state;
+        let mut expected_board_state = GoBoard::new(19).board_state().clone();
         expected_board_state[0][0] = Some(GoPlayer::BLACK);
 
         let expected_output = PlacePieceOutput {
             success: true,
             board_state: Some(expected_board_state),
             next_player: Some(GoPlayer::WHITE),
-            error_msg: None
+            captured_stones: vec![],
+            stones_captured: 0,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]),
+            error: None
         };
 
         assert_eq!(expected_output, output);
@@ -178,11 +179,18 @@ mod tests {
 
         let expected_output = PlacePieceOutput {
             success: false,
-            board_state: Some(game.board.board_state.clone()),
+            board_state: Some(game.board.board_state().clone()),
             next_player: Some(GoPlayer::WHITE),
-            error_msg: Some(String::from(
-                "The player WHITE tried to place a piece on a square occupied by BLACK."
-            ))
+            captured_stones: vec![],
+            stones_captured: 0,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]),
+            error: Some(PlacePieceError::Occupied {
+                player: GoPlayer::WHITE,
+                occupant: GoPlayer::BLACK,
+                row: 0,
+                col: 0
+            })
         };
 
         assert_eq!(expected_output, output);
@@ -207,7 +215,7 @@ mod tests {
             player: GoPlayer::BLACK
         });
 
-        let mut expected_board_state = GoBoard::new(2).board_state;
+        let mut expected_board_state = GoBoard::new(2).board_state().clone();
         expected_board_state[0][1] = Some(GoPlayer::BLACK);
         expected_board_state[1][0] = Some(GoPlayer::BLACK);
 
@@ -215,7 +223,11 @@ mod tests {
             success: true,
             board_state: Some(expected_board_state),
             next_player: Some(GoPlayer::WHITE),
-            error_msg: None
+            captured_stones: vec![(0, 0)],
+            stones_captured: 1,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 1), (GoPlayer::WHITE, 0)]),
+            error: None
         };
 
         assert_eq!(expected_output, output);
@@ -266,7 +278,7 @@ mod tests {
             player: GoPlayer::BLACK
         });
 
-        let mut expected_board_state = GoBoard::new(4).board_state;
+        let mut expected_board_state = GoBoard::new(4).board_state().clone();
         expected_board_state[0][1] = Some(GoPlayer::WHITE);
         expected_board_state[1][0] = Some(GoPlayer::WHITE);
         expected_board_state[2][1] = Some(GoPlayer::WHITE);
@@ -276,7 +288,7 @@ mod tests {
         expected_board_state[2][2] = Some(GoPlayer::BLACK);
         expected_board_state[1][1] = Some(GoPlayer::BLACK);
 
-        assert_eq!(expected_board_state, game.board.board_state);
+        assert_eq!(expected_board_state, *game.board.board_state());
 
         //White takes black
         game.place_piece(PlacePieceInput {
@@ -290,7 +302,7 @@ mod tests {
         //| |O|X| |
         //| | | | |
 
-        let mut expected_board_state = GoBoard::new(4).board_state;
+        let mut expected_board_state = GoBoard::new(4).board_state().clone();
         expected_board_state[0][1] = Some(GoPlayer::WHITE);
         expected_board_state[1][0] = Some(GoPlayer::WHITE);
         expected_board_state[2][1] = Some(GoPlayer::WHITE);
@@ -300,7 +312,7 @@ mod tests {
         expected_board_state[1][3] = Some(GoPlayer::BLACK);
         expected_board_state[2][2] = Some(GoPlayer::BLACK);
 
-        assert_eq!(expected_board_state, game.board.board_state);
+        assert_eq!(expected_board_state, *game.board.board_state());
 
         //Black takes white
         game.place_piece(PlacePieceInput {
@@ -314,7 +326,7 @@ mod tests {
         //| |O|X| |
         //| | | | |
 
-        let mut expected_board_state = GoBoard::new(4).board_state;
+        let mut expected_board_state = GoBoard::new(4).board_state().clone();
         expected_board_state[0][1] = Some(GoPlayer::WHITE);
         expected_board_state[1][0] = Some(GoPlayer::WHITE);
         expected_board_state[2][1] = Some(GoPlayer::WHITE);
@@ -324,7 +336,7 @@ mod tests {
         expected_board_state[2][2] = Some(GoPlayer::BLACK);
         expected_board_state[1][1] = Some(GoPlayer::BLACK);
 
-        assert_eq!(expected_board_state, game.board.board_state);
+        assert_eq!(expected_board_state, *game.board.board_state());
 
         //White violates ko rule
 
@@ -338,14 +350,20 @@ mod tests {
             success: false,
             board_state: Some(expected_board_state),
             next_player: Some(GoPlayer::WHITE),
-            error_msg: Some(String::from(
-                "The player WHITE has violated the Ko rule."
-            ))
+            captured_stones: vec![],
+            stones_captured: 0,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 1), (GoPlayer::WHITE, 1)]),
+            error: Some(PlacePieceError::Ko {
+                player: GoPlayer::WHITE,
+                row: 1,
+                col: 2
+            })
         };
 
         assert_eq!(expected_output, output);
 
-        let mut expected_board_state = GoBoard::new(4).board_state;
+        let mut expected_board_state = GoBoard::new(4).board_state().clone();
         expected_board_state[0][1] = Some(GoPlayer::WHITE);
         expected_board_state[1][0] = Some(GoPlayer::WHITE);
         expected_board_state[2][1] = Some(GoPlayer::WHITE);
@@ -367,9 +385,515 @@ mod tests {
             success: true,
             board_state: Some(expected_board_state),
             next_player: Some(GoPlayer::BLACK),
-            error_msg: None
+            captured_stones: vec![],
+            stones_captured: 0,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 1), (GoPlayer::WHITE, 1)]),
+            error: None
+        };
+
+        assert_eq!(expected_output, output);
+    }
+
+    #[test]
+    fn test_suicide_rejected() {
+        let mut game = GoGame::new(3);
+
+        //|O|X| |
+        //|X| | |
+        //| | |O|
+
+        game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 1,
+            player: GoPlayer::BLACK
+        });
+        game.place_piece(PlacePieceInput {
+            row: 2,
+            col: 2,
+            player: GoPlayer::WHITE
+        });
+        game.place_piece(PlacePieceInput {
+            row: 1,
+            col: 0,
+            player: GoPlayer::BLACK
+        });
+
+        let board_before_suicide = game.board.board_state().clone();
+
+        let output = game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 0,
+            player: GoPlayer::WHITE
+        });
+
+        let expected_output = PlacePieceOutput {
+            success: false,
+            board_state: Some(board_before_suicide.clone()),
+            next_player: Some(GoPlayer::WHITE),
+            captured_stones: vec![],
+            stones_captured: 0,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]),
+            error: Some(PlacePieceError::Suicide {
+                player: GoPlayer::WHITE,
+                row: 0,
+                col: 0
+            })
+        };
+
+        assert_eq!(expected_output, output);
+        assert_eq!(board_before_suicide, *game.board.board_state());
+    }
+
+    #[test]
+    fn test_suicide_allowed_removes_own_group() {
+        let mut game = GoGame::new(3);
+        game.suicide_allowed = true;
+
+        //|O|O|X|
+        //|O|X|X|
+        //|X| |O|
+
+        game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 2,
+            player: GoPlayer::BLACK
+        });
+        game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 0,
+            player: GoPlayer::WHITE
+        });
+        game.place_piece(PlacePieceInput {
+            row: 1,
+            col: 1,
+            player: GoPlayer::BLACK
+        });
+        game.place_piece(PlacePieceInput {
+            row: 2,
+            col: 2,
+            player: GoPlayer::WHITE
+        });
+        game.place_piece(PlacePieceInput {
+            row: 2,
+            col: 0,
+            player: GoPlayer::BLACK
+        });
+        game.place_piece(PlacePieceInput {
+            row: 1,
+            col: 0,
+            player: GoPlayer::WHITE
+        });
+        game.place_piece(PlacePieceInput {
+            row: 1,
+            col: 2,
+            player: GoPlayer::BLACK
+        });
+
+        let output = game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 1,
+            player: GoPlayer::WHITE
+        });
+
+        let mut expected_board_state = GoBoard::new(3).board_state().clone();
+        expected_board_state[0][2] = Some(GoPlayer::BLACK);
+        expected_board_state[1][1] = Some(GoPlayer::BLACK);
+        expected_board_state[1][2] = Some(GoPlayer::BLACK);
+        expected_board_state[2][0] = Some(GoPlayer::BLACK);
+        expected_board_state[2][2] = Some(GoPlayer::WHITE);
+
+        let expected_output = PlacePieceOutput {
+            success: true,
+            board_state: Some(expected_board_state),
+            next_player: Some(GoPlayer::BLACK),
+            captured_stones: vec![],
+            stones_captured: 0,
+            suicided_stones: vec![(0, 0), (0, 1), (1, 0)],
+            prisoners: HashMap::from([(GoPlayer::BLACK, 3), (GoPlayer::WHITE, 0)]),
+            error: None
         };
 
         assert_eq!(expected_output, output);
     }
+
+    #[test]
+    fn test_capture_is_not_suicide() {
+        let mut game = GoGame::new(4);
+
+        //| |X|O| |
+        //|X|O| | |
+        //|O| | | |
+        //| | |X|X|
+
+        let moves = [
+            (0, 1, GoPlayer::BLACK),
+            (1, 1, GoPlayer::WHITE),
+            (1, 0, GoPlayer::BLACK),
+            (0, 2, GoPlayer::WHITE),
+            (3, 3, GoPlayer::BLACK),
+            (2, 0, GoPlayer::WHITE),
+            (3, 2, GoPlayer::BLACK),
+        ];
+
+        for (row, col, player) in moves {
+            assert!(game.place_piece(PlacePieceInput { row, col, player }).success);
+        }
+
+        //White fills the eye and takes both black stones
+        let output = game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 0,
+            player: GoPlayer::WHITE
+        });
+
+        let mut expected_board_state = GoBoard::new(4).board_state().clone();
+        expected_board_state[0][0] = Some(GoPlayer::WHITE);
+        expected_board_state[0][2] = Some(GoPlayer::WHITE);
+        expected_board_state[1][1] = Some(GoPlayer::WHITE);
+        expected_board_state[2][0] = Some(GoPlayer::WHITE);
+
+        expected_board_state[3][2] = Some(GoPlayer::BLACK);
+        expected_board_state[3][3] = Some(GoPlayer::BLACK);
+
+        let expected_output = PlacePieceOutput {
+            success: true,
+            board_state: Some(expected_board_state),
+            next_player: Some(GoPlayer::BLACK),
+            captured_stones: vec![(0, 1), (1, 0)],
+            stones_captured: 2,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 2)]),
+            error: None
+        };
+
+        assert_eq!(expected_output, output);
+    }
+
+    #[test]
+    fn test_capture_on_rectangular_board() {
+        let mut game = GoGame::with_dimensions(2, 5).unwrap();
+
+        //| | | |X|O|
+        //| | | | |X|
+
+        let moves = [
+            (0, 3, GoPlayer::BLACK),
+            (0, 4, GoPlayer::WHITE),
+        ];
+
+        for (row, col, player) in moves {
+            assert!(game.place_piece(PlacePieceInput { row, col, player }).success);
+        }
+
+        let output = game.place_piece(PlacePieceInput {
+            row: 1,
+            col: 4,
+            player: GoPlayer::BLACK
+        });
+
+        let mut expected_board_state = GoBoard::with_dimensions(2, 5).unwrap().board_state().clone();
+        expected_board_state[0][3] = Some(GoPlayer::BLACK);
+        expected_board_state[1][4] = Some(GoPlayer::BLACK);
+
+        let expected_output = PlacePieceOutput {
+            success: true,
+            board_state: Some(expected_board_state),
+            next_player: Some(GoPlayer::WHITE),
+            captured_stones: vec![(0, 4)],
+            stones_captured: 1,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 1), (GoPlayer::WHITE, 0)]),
+            error: None
+        };
+
+        assert_eq!(expected_output, output);
+
+        let output = game.place_piece(PlacePieceInput {
+            row: 4,
+            col: 1,
+            player: GoPlayer::WHITE
+        });
+
+        assert_eq!(Some(PlacePieceError::OutOfBounds { row: 4, col: 1 }), output.error);
+    }
+
+    fn setup_triple_ko(game: &mut GoGame) {
+        //| |X|O| | | |O|X| | | |X|O| |
+        //|X|O| |O| |O|X| |X| |X|O| |O|
+        //| |X|O| | | |O|X| | | |X|O| |
+
+        for offset in [0, 10] {
+            game.board.place(0, offset + 1, &GoPlayer::BLACK);
+            game.board.place(1, offset, &GoPlayer::BLACK);
+            game.board.place(2, offset + 1, &GoPlayer::BLACK);
+
+            game.board.place(1, offset + 1, &GoPlayer::WHITE);
+            game.board.place(0, offset + 2, &GoPlayer::WHITE);
+            game.board.place(2, offset + 2, &GoPlayer::WHITE);
+            game.board.place(1, offset + 3, &GoPlayer::WHITE);
+        }
+
+        game.board.place(0, 6, &GoPlayer::WHITE);
+        game.board.place(1, 5, &GoPlayer::WHITE);
+        game.board.place(2, 6, &GoPlayer::WHITE);
+
+        game.board.place(1, 6, &GoPlayer::BLACK);
+        game.board.place(0, 7, &GoPlayer::BLACK);
+        game.board.place(2, 7, &GoPlayer::BLACK);
+        game.board.place(1, 8, &GoPlayer::BLACK);
+
+        game.record_position();
+    }
+
+    fn play_triple_ko_cycle(game: &mut GoGame) -> PlacePieceOutput {
+        let moves = [
+            (1, 2, GoPlayer::BLACK),
+            (1, 7, GoPlayer::WHITE),
+            (1, 12, GoPlayer::BLACK),
+            (1, 1, GoPlayer::WHITE),
+            (1, 6, GoPlayer::BLACK),
+        ];
+
+        for (row, col, player) in moves {
+            assert!(game.place_piece(PlacePieceInput { row, col, player }).success);
+        }
+
+        //White's last capture would recreate the starting position
+        game.place_piece(PlacePieceInput {
+            row: 1,
+            col: 11,
+            player: GoPlayer::WHITE
+        })
+    }
+
+    #[test]
+    fn test_triple_ko_allowed_by_simple_ko() {
+        let mut game = GoGame::new(14);
+        setup_triple_ko(&mut game);
+        let starting_board_state = game.board.board_state().clone();
+
+        let output = play_triple_ko_cycle(&mut game);
+
+        assert!(output.success);
+        assert_eq!(starting_board_state, *game.board.board_state());
+    }
+
+    #[test]
+    fn test_triple_ko_violates_positional_superko() {
+        let mut game = GoGame::new(14);
+        game.ko_rule = KoRule::PositionalSuperko;
+        setup_triple_ko(&mut game);
+
+        let output = play_triple_ko_cycle(&mut game);
+
+        assert!(!output.success);
+        assert_eq!(
+            Some(PlacePieceError::Ko {
+                player: GoPlayer::WHITE,
+                row: 1,
+                col: 11
+            }),
+            output.error
+        );
+        assert_eq!(Some(GoPlayer::WHITE), output.next_player);
+        assert_eq!(Some(GoPlayer::BLACK), game.board.board_state()[1][12]);
+    }
+
+    #[test]
+    fn test_triple_ko_violates_situational_superko() {
+        let mut game = GoGame::new(14);
+        game.ko_rule = KoRule::SituationalSuperko;
+        setup_triple_ko(&mut game);
+
+        let output = play_triple_ko_cycle(&mut game);
+
+        assert!(!output.success);
+        assert_eq!(
+            Some(PlacePieceError::Ko {
+                player: GoPlayer::WHITE,
+                row: 1,
+                col: 11
+            }),
+            output.error
+        );
+    }
+
+    #[test]
+    fn test_superko_player_to_move() {
+        //The board with a white stone at (0, 0) was previously seen with
+        //White to move, so recreating it with Black to move is only a
+        //repetition under positional superko
+        let mut positional_game = GoGame::new(5);
+        positional_game.ko_rule = KoRule::PositionalSuperko;
+
+        let mut situational_game = GoGame::new(5);
+        situational_game.ko_rule = KoRule::SituationalSuperko;
+
+        for game in [&mut positional_game, &mut situational_game] {
+            game.board.place(0, 0, &GoPlayer::WHITE);
+            game.current_player = GoPlayer::WHITE;
+            game.record_position();
+
+            game.board.remove(0, 0);
+        }
+
+        let positional_output = positional_game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 0,
+            player: GoPlayer::WHITE
+        });
+        let situational_output = situational_game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 0,
+            player: GoPlayer::WHITE
+        });
+
+        assert!(!positional_output.success);
+        assert!(situational_output.success);
+    }
+
+    #[test]
+    fn test_prisoners_counted() {
+        let mut game = GoGame::new(2);
+        assert_eq!(0, game.prisoners[&GoPlayer::BLACK]);
+        assert_eq!(0, game.prisoners[&GoPlayer::WHITE]);
+
+        game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 1,
+            player: GoPlayer::BLACK
+        });
+        game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 0,
+            player: GoPlayer::WHITE
+        });
+        game.place_piece(PlacePieceInput {
+            row: 1,
+            col: 0,
+            player: GoPlayer::BLACK
+        });
+
+        assert_eq!(1, game.prisoners[&GoPlayer::BLACK]);
+        assert_eq!(0, game.prisoners[&GoPlayer::WHITE]);
+    }
+
+    #[test]
+    fn test_suicided_stones_are_prisoners_for_opponent() {
+        let mut game = GoGame::new(3);
+        game.suicide_allowed = true;
+
+        let moves = [
+            (0, 1, GoPlayer::BLACK),
+            (2, 2, GoPlayer::WHITE),
+            (1, 0, GoPlayer::BLACK),
+        ];
+
+        for (row, col, player) in moves {
+            assert!(game.place_piece(PlacePieceInput { row, col, player }).success);
+        }
+
+        let output = game.place_piece(PlacePieceInput { row: 0, col: 0, player: GoPlayer::WHITE });
+
+        assert!(output.success);
+        assert_eq!(Vec::<(u32, u32)>::new(), output.captured_stones);
+        assert_eq!(0, output.stones_captured);
+        assert_eq!(vec![(0, 0)], output.suicided_stones);
+        assert_eq!(1, game.prisoners[&GoPlayer::BLACK]);
+        assert_eq!(0, game.prisoners[&GoPlayer::WHITE]);
+    }
+
+    #[test]
+    fn test_place_piece_out_of_bounds() {
+        let mut game = GoGame::new(19);
+
+        let output = game.place_piece(PlacePieceInput {
+            row: 19,
+            col: 3,
+            player: GoPlayer::BLACK
+        });
+
+        let expected_output = PlacePieceOutput {
+            success: false,
+            board_state: Some(GoBoard::new(19).board_state().clone()),
+            next_player: Some(GoPlayer::BLACK),
+            captured_stones: vec![],
+            stones_captured: 0,
+            suicided_stones: Vec::new(),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]),
+            error: Some(PlacePieceError::OutOfBounds {
+                row: 19,
+                col: 3
+            })
+        };
+
+        assert_eq!(expected_output, output);
+        assert!(game.move_history.is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn test_random_inputs_never_panic(
+            size in 1u32..10,
+            suicide_allowed in any::<bool>(),
+            inputs in prop::collection::vec(
+                (prop_oneof![0u32..12, any::<u32>()], prop_oneof![0u32..12, any::<u32>()], any::<bool>()),
+                0..100
+            )
+        ) {
+            let mut game = GoGame::new(size);
+            game.suicide_allowed = suicide_allowed;
+
+            for (row, col, is_black) in inputs {
+                let player = if is_black { GoPlayer::BLACK } else { GoPlayer::WHITE };
+
+                let output = game.place_piece(PlacePieceInput { row, col, player });
+
+                if row >= size || col >= size {
+                    prop_assert!(!output.success);
+                }
+                prop_assert_eq!(output.success, output.error.is_none());
+            }
+        }
+
+        #[test]
+        fn test_captures_match_aggregator(
+            size in 2u32..8,
+            inputs in prop::collection::vec((0u32..8, 0u32..8), 0..150)
+        ) {
+            let mut game = GoGame::new(size);
+
+            for (row, col) in inputs {
+                let player = game.current_player;
+                let opponent = match player {
+                    GoPlayer::BLACK => GoPlayer::WHITE,
+                    GoPlayer::WHITE => GoPlayer::BLACK
+                };
+
+                let mut expected_board = game.board.clone();
+                expected_board.place(row, col, &player);
+                let mut expected_captures = GroupLibertiesAggregator::get_group_liberties(&expected_board)[&opponent]
+                    .iter()
+                    .filter(|(_, liberties)| liberties.is_empty())
+                    .flat_map(|(group, _)| group.iter().copied())
+                    .collect::<Vec<_>>();
+                expected_captures.sort();
+
+                let output = game.place_piece(PlacePieceInput { row, col, player });
+
+                if output.success {
+                    prop_assert_eq!(expected_captures, output.captured_stones);
+                }
+                for (_, groups) in GroupLibertiesAggregator::get_group_liberties(&game.board) {
+                    for (group, liberties) in groups {
+                        let (row, col) = *group.iter().next().unwrap();
+                        prop_assert_eq!(liberties.len() as u32, game.board.chain_at(row, col).unwrap().liberty_count());
+                        prop_assert!(!liberties.is_empty());
+                    }
+                }
+            }
+        }
+    }
 }
\ No newline at end of file
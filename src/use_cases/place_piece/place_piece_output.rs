@@ -1,9 +1,17 @@
+use std::collections::HashMap;
+
 use crate::domain::go_board::{GoBoardState, GoPlayer};
 
+use super::place_piece_error::PlacePieceError;
+
 #[derive(PartialEq, Debug)]
 pub struct PlacePieceOutput {
     pub success: bool,
     pub board_state: Option<GoBoardState>,
     pub next_player: Option<GoPlayer>,
-    pub error_msg: Option<String>
+    pub captured_stones: Vec<(u32, u32)>,
+    pub stones_captured: u32,
+    pub suicided_stones: Vec<(u32, u32)>,
+    pub prisoners: HashMap<GoPlayer, u32>,
+    pub error: Option<PlacePieceError>
 }
\ No newline at end of file
@@ -0,0 +1,6 @@
+pub mod redo_move_input;
+pub mod redo_move_output;
+pub mod redo_move_error;
+
+pub mod redo_move_requester;
+pub mod redo_move_interactor;
\ No newline at end of file
@@ -0,0 +1,48 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::use_cases::place_piece::place_piece_error::PlacePieceError;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum RedoMoveError {
+    NoMoves,
+    /// The move can't be played again, e.g. because the board was set up
+    /// differently since it was undone.
+    IllegalMove(PlacePieceError)
+}
+
+impl fmt::Display for RedoMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RedoMoveError::NoMoves => write!(f, "There are no moves to redo."),
+            RedoMoveError::IllegalMove(error) => write!(f, "{}", error)
+        }
+    }
+}
+
+impl Error for RedoMoveError {}
+
+impl From<PlacePieceError> for RedoMoveError {
+    fn from(error: PlacePieceError) -> Self {
+        RedoMoveError::IllegalMove(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::go_board::GoPlayer;
+
+    use super::*;
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!("There are no moves to redo.", RedoMoveError::NoMoves.to_string());
+        assert_eq!(
+            "The player WHITE tried to move when it is BLACK's turn.",
+            RedoMoveError::IllegalMove(PlacePieceError::WrongTurn {
+                player: GoPlayer::WHITE,
+                current_player: GoPlayer::BLACK
+            }).to_string()
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use crate::domain::go_game::{GoGame, GoMove};
+use crate::use_cases::pass_turn::pass_turn_input::PassTurnInput;
+use crate::use_cases::pass_turn::pass_turn_requester::PassTurnRequester;
+use crate::use_cases::place_piece::place_piece_error::PlacePieceError;
+use crate::use_cases::place_piece::place_piece_input::PlacePieceInput;
+use crate::use_cases::place_piece::place_piece_requester::PlacePieceRequester;
+
+use super::redo_move_error::RedoMoveError;
+use super::redo_move_input::RedoMoveInput;
+use super::redo_move_requester::RedoMoveRequester;
+use super::redo_move_output::RedoMoveOutput;
+
+impl RedoMoveRequester for GoGame {
+    fn redo_move(&mut self, _redo_move_input: RedoMoveInput) -> RedoMoveOutput {
+        let record = match self.undone_moves.pop() {
+            Some(record) => record,
+            None => {
+                return RedoMoveOutput {
+                    success: false,
+                    board_state: Some(self.board.board_state().clone()),
+                    next_player: Some(self.current_player),
+                    redone_move: None,
+                    prisoners: self.prisoners.clone(),
+                    error: Some(RedoMoveError::NoMoves)
+                };
+            }
+        };
+
+        // Replaying the move clears the redo stack like any new move would,
+        // so hold on to the rest of it while the move is played again.
+        let undone_moves = std::mem::take(&mut self.undone_moves);
+
+        let error = match record.chosen_move {
+            GoMove::Place(row, col) => self
+                .place_piece(PlacePieceInput { row, col, player: record.player })
+                .error,
+            // A pass can only fail because the game is over or it isn't
+            // the player's turn.
+            GoMove::Pass => {
+                let output = self.pass_turn(PassTurnInput { player: record.player });
+                match (output.success, output.game_over) {
+                    (true, _) => None,
+                    (false, true) => Some(PlacePieceError::GameOver),
+                    (false, false) => Some(PlacePieceError::WrongTurn {
+                        player: record.player,
+                        current_player: self.current_player
+                    })
+                }
+            }
+        };
+
+        self.undone_moves = undone_moves;
+
+        let redone_move = (record.player, record.chosen_move);
+        if error.is_some() {
+            self.undone_moves.push(record);
+        }
+
+        RedoMoveOutput {
+            success: error.is_none(),
+            board_state: Some(self.board.board_state().clone()),
+            next_player: Some(self.current_player),
+            redone_move: error.is_none().then_some(redone_move),
+            prisoners: self.prisoners.clone(),
+            error: error.map(RedoMoveError::from)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::domain::go_board::{GoBoard, GoPlayer};
+    use crate::domain::setup::Setup;
+    use crate::use_cases::undo_move::undo_move_input::UndoMoveInput;
+    use crate::use_cases::undo_move::undo_move_requester::UndoMoveRequester;
+
+    use super::*;
+
+    #[test]
+    fn test_redo_without_undo() {
+        let mut game = GoGame::new(9);
+
+        let output = game.redo_move(RedoMoveInput);
+
+        let expected_output = RedoMoveOutput {
+            success: false,
+            board_state: Some(GoBoard::new(9).board_state().clone()),
+            next_player: Some(GoPlayer::BLACK),
+            redone_move: None,
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]),
+            error: Some(RedoMoveError::NoMoves)
+        };
+
+        assert_eq!(expected_output, output);
+    }
+
+    #[test]
+    fn test_undo_then_redo_restores_game() {
+        let mut game = GoGame::new(2);
+
+        let moves = [
+            (0, 1, GoPlayer::BLACK),
+            (0, 0, GoPlayer::WHITE),
+            (1, 0, GoPlayer::BLACK),
+        ];
+
+        for (row, col, player) in moves {
+            game.place_piece(PlacePieceInput { row, col, player });
+        }
+        game.pass_turn(PassTurnInput {
+            player: GoPlayer::WHITE
+        });
+
+        let board_after_moves = game.board.clone();
+        let two_previous_states_after_moves = game.two_previous_states.clone();
+        let move_history_after_moves = game.move_history.clone();
+
+        for _ in 0..4 {
+            assert!(game.undo_move(UndoMoveInput).success);
+        }
+        assert_eq!(GoBoard::new(2), game.board);
+        assert_eq!(GoPlayer::BLACK, game.current_player);
+
+        for _ in 0..3 {
+            assert!(game.redo_move(RedoMoveInput).success);
+        }
+
+        let output = game.redo_move(RedoMoveInput);
+
+        let expected_output = RedoMoveOutput {
+            success: true,
+            board_state: Some(board_after_moves.board_state().clone()),
+            next_player: Some(GoPlayer::BLACK),
+            redone_move: Some((GoPlayer::WHITE, GoMove::Pass)),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 1), (GoPlayer::WHITE, 0)]),
+            error: None
+        };
+
+        assert_eq!(expected_output, output);
+        assert_eq!(board_after_moves, game.board);
+        assert_eq!(two_previous_states_after_moves, game.two_previous_states);
+        assert_eq!(move_history_after_moves, game.move_history);
+        assert!(game.undone_moves.is_empty());
+    }
+
+    #[test]
+    fn test_new_move_clears_redo() {
+        let mut game = GoGame::new(9);
+        game.place_piece(PlacePieceInput {
+            row: 4,
+            col: 4,
+            player: GoPlayer::BLACK
+        });
+        game.undo_move(UndoMoveInput);
+
+        game.place_piece(PlacePieceInput {
+            row: 2,
+            col: 2,
+            player: GoPlayer::BLACK
+        });
+
+        assert!(!game.redo_move(RedoMoveInput).success);
+        assert_eq!(vec![(GoPlayer::BLACK, GoMove::Place(2, 2))], game.moves());
+    }
+
+    #[test]
+    fn test_undo_then_redo_around_setup() {
+        let mut game = GoGame::new(5);
+        game.place_piece(PlacePieceInput {
+            row: 2,
+            col: 2,
+            player: GoPlayer::BLACK
+        });
+        game.apply_setup(Setup {
+            black_stones: vec![(0, 0)],
+            white_stones: vec![(4, 4)],
+            player: Some(GoPlayer::BLACK),
+            ..Setup::default()
+        })
+        .unwrap();
+        game.place_piece(PlacePieceInput {
+            row: 1,
+            col: 1,
+            player: GoPlayer::BLACK
+        });
+
+        let board_after_moves = game.board.clone();
+        let setup_history_after_moves = game.setup_history.clone();
+        let move_history_after_moves = game.move_history.clone();
+
+        assert!(game.undo_move(UndoMoveInput).success);
+        assert!(!game.undo_move(UndoMoveInput).success);
+        assert_eq!(Some(GoPlayer::BLACK), game.board.board_state()[0][0]);
+        assert_eq!(GoPlayer::BLACK, game.current_player);
+
+        assert!(game.redo_move(RedoMoveInput).success);
+
+        assert_eq!(board_after_moves, game.board);
+        assert_eq!(setup_history_after_moves, game.setup_history);
+        assert_eq!(move_history_after_moves, game.move_history);
+        assert_eq!(GoPlayer::WHITE, game.current_player);
+    }
+
+    #[test]
+    fn test_redo_onto_set_up_stone() {
+        let mut game = GoGame::new(5);
+        game.place_piece(PlacePieceInput {
+            row: 2,
+            col: 2,
+            player: GoPlayer::BLACK
+        });
+        game.undo_move(UndoMoveInput);
+        game.apply_setup(Setup {
+            white_stones: vec![(2, 2)],
+            ..Setup::default()
+        })
+        .unwrap();
+
+        let output = game.redo_move(RedoMoveInput);
+
+        assert_eq!(
+            Some(RedoMoveError::IllegalMove(PlacePieceError::Occupied {
+                player: GoPlayer::BLACK,
+                occupant: GoPlayer::WHITE,
+                row: 2,
+                col: 2
+            })),
+            output.error
+        );
+        assert_eq!(1, game.undone_moves.len());
+    }
+}
@@ -0,0 +1,5 @@
+use super::{redo_move_output::RedoMoveOutput, redo_move_input::RedoMoveInput};
+
+pub trait RedoMoveRequester {
+    fn redo_move(&mut self, redo_move_input: RedoMoveInput) -> RedoMoveOutput;
+}
@@ -0,0 +1,6 @@
+pub mod resign_input;
+pub mod resign_output;
+pub mod resign_error;
+
+pub mod resign_requester;
+pub mod resign_interactor;
\ No newline at end of file
@@ -0,0 +1,27 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ResignError {
+    GameOver
+}
+
+impl fmt::Display for ResignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResignError::GameOver => write!(f, "The game is over.")
+        }
+    }
+}
+
+impl Error for ResignError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!("The game is over.", ResignError::GameOver.to_string());
+    }
+}
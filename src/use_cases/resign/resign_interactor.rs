@@ -0,0 +1,146 @@
+use crate::domain::game_status::{GameResult, GameStatus};
+use crate::domain::go_board::GoPlayer;
+use crate::domain::go_game::GoGame;
+
+use super::resign_error::ResignError;
+use super::resign_input::ResignInput;
+use super::resign_requester::ResignRequester;
+use super::resign_output::ResignOutput;
+
+/// Either player can resign at any point until the game is finished, on
+/// their turn or not and also while the game is being scored. The result
+/// is copied into the game info so that SGF records carry it.
+impl ResignRequester for GoGame {
+    fn resign(&mut self, resign_input: ResignInput) -> ResignOutput {
+        if let Some(result) = self.result() {
+            return ResignOutput {
+                success: false,
+                result: Some(result),
+                error: Some(ResignError::GameOver)
+            };
+        }
+
+        let winner = match resign_input.player {
+            GoPlayer::BLACK => GoPlayer::WHITE,
+            GoPlayer::WHITE => GoPlayer::BLACK
+        };
+        let result = GameResult::Resignation { winner };
+
+        self.status = GameStatus::Finished { result };
+        self.game_info.result = Some(result.to_string());
+
+        ResignOutput {
+            success: true,
+            result: Some(result),
+            error: None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::use_cases::pass_turn::pass_turn_input::PassTurnInput;
+    use crate::use_cases::pass_turn::pass_turn_requester::PassTurnRequester;
+    use crate::use_cases::place_piece::place_piece_error::PlacePieceError;
+    use crate::use_cases::place_piece::place_piece_input::PlacePieceInput;
+    use crate::use_cases::place_piece::place_piece_requester::PlacePieceRequester;
+    use crate::use_cases::undo_move::undo_move_input::UndoMoveInput;
+    use crate::use_cases::undo_move::undo_move_requester::UndoMoveRequester;
+
+    use super::*;
+
+    #[test]
+    fn test_black_resigns() {
+        let mut game = GoGame::new(9);
+
+        let output = game.resign(ResignInput {
+            player: GoPlayer::BLACK
+        });
+
+        let expected_output = ResignOutput {
+            success: true,
+            result: Some(GameResult::Resignation { winner: GoPlayer::WHITE }),
+            error: None
+        };
+
+        assert_eq!(expected_output, output);
+        assert_eq!(Some(GameResult::Resignation { winner: GoPlayer::WHITE }), game.result());
+        assert_eq!(Some(String::from("W+R")), game.game_info.result);
+        assert!(game.is_over());
+    }
+
+    #[test]
+    fn test_resign_out_of_turn() {
+        let mut game = GoGame::new(9);
+
+        let output = game.resign(ResignInput {
+            player: GoPlayer::WHITE
+        });
+
+        assert!(output.success);
+        assert_eq!(Some(String::from("B+R")), game.game_info.result);
+    }
+
+    #[test]
+    fn test_resign_while_scoring() {
+        let mut game = GoGame::new(9);
+        game.pass_turn(PassTurnInput {
+            player: GoPlayer::BLACK
+        });
+        game.pass_turn(PassTurnInput {
+            player: GoPlayer::WHITE
+        });
+        assert_eq!(GameStatus::Scoring, game.status);
+
+        let output = game.resign(ResignInput {
+            player: GoPlayer::WHITE
+        });
+
+        assert!(output.success);
+        assert_eq!(
+            GameStatus::Finished { result: GameResult::Resignation { winner: GoPlayer::BLACK } },
+            game.status
+        );
+    }
+
+    #[test]
+    fn test_no_moves_after_resignation() {
+        let mut game = GoGame::new(9);
+        game.place_piece(PlacePieceInput {
+            row: 2,
+            col: 2,
+            player: GoPlayer::BLACK
+        });
+        game.resign(ResignInput {
+            player: GoPlayer::WHITE
+        });
+
+        let output = game.place_piece(PlacePieceInput {
+            row: 3,
+            col: 3,
+            player: GoPlayer::WHITE
+        });
+        assert_eq!(Some(PlacePieceError::GameOver), output.error);
+
+        let output = game.pass_turn(PassTurnInput {
+            player: GoPlayer::WHITE
+        });
+        assert!(!output.success);
+
+        let output = game.undo_move(UndoMoveInput);
+        assert!(!output.success);
+        assert_eq!(1, game.move_history.len());
+
+        let output = game.resign(ResignInput {
+            player: GoPlayer::BLACK
+        });
+
+        let expected_output = ResignOutput {
+            success: false,
+            result: Some(GameResult::Resignation { winner: GoPlayer::BLACK }),
+            error: Some(ResignError::GameOver)
+        };
+
+        assert_eq!(expected_output, output);
+    }
+}
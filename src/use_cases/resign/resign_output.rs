@@ -0,0 +1,10 @@
+use crate::domain::game_status::GameResult;
+
+use super::resign_error::ResignError;
+
+#[derive(PartialEq, Debug)]
+pub struct ResignOutput {
+    pub success: bool,
+    pub result: Option<GameResult>,
+    pub error: Option<ResignError>
+}
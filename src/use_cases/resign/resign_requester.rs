@@ -0,0 +1,5 @@
+use super::{resign_output::ResignOutput, resign_input::ResignInput};
+
+pub trait ResignRequester {
+    fn resign(&mut self, resign_input: ResignInput) -> ResignOutput;
+}
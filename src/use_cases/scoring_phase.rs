@@ -0,0 +1,6 @@
+pub mod scoring_phase_input;
+pub mod scoring_phase_output;
+pub mod scoring_phase_error;
+
+pub mod scoring_phase_requester;
+pub mod scoring_phase_interactor;
\ No newline at end of file
@@ -0,0 +1,46 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::domain::go_board::OutOfBoundsError;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ScoringPhaseError {
+    NotScoring,
+    OutOfBounds { row: u32, col: u32 },
+    NoStone { row: u32, col: u32 }
+}
+
+impl fmt::Display for ScoringPhaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScoringPhaseError::NotScoring => write!(f, "The game is not being scored."),
+            ScoringPhaseError::OutOfBounds { row, col } => write!(f,
+                "{}", OutOfBoundsError { row: *row, col: *col }),
+            ScoringPhaseError::NoStone { row, col } => write!(f,
+                "There is no stone at ({}, {}).", row, col)
+        }
+    }
+}
+
+impl Error for ScoringPhaseError {}
+
+impl From<OutOfBoundsError> for ScoringPhaseError {
+    fn from(error: OutOfBoundsError) -> Self {
+        ScoringPhaseError::OutOfBounds { row: error.row, col: error.col }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!("The game is not being scored.", ScoringPhaseError::NotScoring.to_string());
+        assert_eq!(
+            "The point (4, 0) is outside the board.",
+            ScoringPhaseError::OutOfBounds { row: 4, col: 0 }.to_string()
+        );
+        assert_eq!("There is no stone at (1, 0).", ScoringPhaseError::NoStone { row: 1, col: 0 }.to_string());
+    }
+}
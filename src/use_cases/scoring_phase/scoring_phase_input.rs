@@ -0,0 +1,16 @@
+use crate::domain::go_board::GoPlayer;
+
+pub enum ScoringAction {
+    /// Marks the whole group at (row, col) dead, or alive again.
+    ToggleGroup { row: u32, col: u32 },
+    /// Agrees to the stones marked dead. The game is scored once both
+    /// players have accepted the same marking.
+    Accept,
+    /// Disagrees with the marking and carries on playing.
+    ResumePlay
+}
+
+pub struct ScoringPhaseInput {
+    pub player: GoPlayer,
+    pub action: ScoringAction
+}
@@ -0,0 +1,283 @@
+use crate::domain::game_status::{GameResult, GameStatus};
+use crate::domain::go_game::GoGame;
+use crate::domain::scoring::ScoreResult;
+use crate::domain::util::group_liberties_aggregator::GroupLibertiesAggregator;
+
+use super::scoring_phase_error::ScoringPhaseError;
+use super::scoring_phase_input::{ScoringAction, ScoringPhaseInput};
+use super::scoring_phase_requester::ScoringPhaseRequester;
+use super::scoring_phase_output::ScoringPhaseOutput;
+
+/// After two passes the players settle which stones are dead, the way
+/// online servers do it: either player toggles whole groups dead or alive,
+/// the game is scored once both accept the same marking, and either one can
+/// disagree and go back to playing instead.
+impl ScoringPhaseRequester for GoGame {
+    fn scoring_phase(&mut self, scoring_phase_input: ScoringPhaseInput) -> ScoringPhaseOutput {
+        if self.status != GameStatus::Scoring {
+            return self.scoring_phase_output(false, None, Some(ScoringPhaseError::NotScoring));
+        }
+
+        match scoring_phase_input.action {
+            ScoringAction::ToggleGroup { row, col } => {
+                let player = match self.board.get(row, col) {
+                    Ok(Some(player)) => player,
+                    Ok(None) => return self.scoring_phase_output(false, None, Some(ScoringPhaseError::NoStone { row, col })),
+                    Err(error) => return self.scoring_phase_output(false, None, Some(error.into()))
+                };
+
+                let (group, _) = GroupLibertiesAggregator::get_group_liberties(&self.board)
+                    .remove(&player)
+                    .unwrap()
+                    .into_iter()
+                    .find(|(group, _)| group.contains(&(row, col)))
+                    .unwrap();
+                self.dead_stone_marking.toggle_group(&group);
+
+                self.scoring_phase_output(true, None, None)
+            }
+            ScoringAction::Accept => {
+                self.dead_stone_marking.accept(scoring_phase_input.player);
+                if !self.dead_stone_marking.accepted_by_both() {
+                    return self.scoring_phase_output(true, None, None);
+                }
+
+                // Only stones on the board can be marked dead, so scoring
+                // can't fail.
+                let dead_stones = self.dead_stone_marking.sorted_dead_stones();
+                let score = self.score(self.scoring_method, &dead_stones, self.komi).unwrap();
+                let result = GameResult::from_score(&score);
+
+                self.status = GameStatus::Finished { result };
+                self.game_info.result = Some(result.to_string());
+
+                self.scoring_phase_output(true, Some(score), None)
+            }
+            ScoringAction::ResumePlay => {
+                self.resume_play();
+
+                self.scoring_phase_output(true, None, None)
+            }
+        }
+    }
+}
+
+impl GoGame {
+    fn scoring_phase_output(
+        &self,
+        success: bool,
+        score: Option<ScoreResult>,
+        error: Option<ScoringPhaseError>
+    ) -> ScoringPhaseOutput {
+        ScoringPhaseOutput {
+            success,
+            status: self.status,
+            dead_stones: self.dead_stone_marking.sorted_dead_stones(),
+            score,
+            error
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::game_status::DeadStoneMarking;
+    use crate::domain::go_board::GoPlayer;
+    use crate::domain::go_game::GoMove;
+    use crate::domain::scoring::ScoringMethod;
+    use crate::use_cases::pass_turn::pass_turn_input::PassTurnInput;
+    use crate::use_cases::pass_turn::pass_turn_requester::PassTurnRequester;
+    use crate::use_cases::place_piece::place_piece_input::PlacePieceInput;
+    use crate::use_cases::place_piece::place_piece_requester::PlacePieceRequester;
+    use crate::use_cases::undo_move::undo_move_input::UndoMoveInput;
+    use crate::use_cases::undo_move::undo_move_requester::UndoMoveRequester;
+
+    use super::*;
+
+    /// Plays out a 4x4 game that ends in two passes:
+    ///
+    ///  |O|X|O| |
+    ///  | |X|O| |
+    ///  |X|X|O|X|
+    ///  | |X|O| |
+    fn game_being_scored() -> GoGame {
+        let mut game = GoGame::new(4);
+        let moves = [(0, 1), (0, 2), (1, 1), (1, 2), (2, 1), (2, 2), (3, 1), (3, 2), (2, 0), (0, 0)];
+        for (index, (row, col)) in moves.iter().enumerate() {
+            let player = match index % 2 {
+                0 => GoPlayer::BLACK,
+                _ => GoPlayer::WHITE
+            };
+            game.place_piece(PlacePieceInput { row: *row, col: *col, player });
+        }
+        game.place_piece(PlacePieceInput { row: 2, col: 3, player: GoPlayer::BLACK });
+        game.pass_turn(PassTurnInput { player: GoPlayer::WHITE });
+        game.pass_turn(PassTurnInput { player: GoPlayer::BLACK });
+
+        game
+    }
+
+    /// The same game with the estimated dead stones cleared, so that the
+    /// marking is all down to the players.
+    fn game_being_scored_unmarked() -> GoGame {
+        let mut game = game_being_scored();
+        game.dead_stone_marking = DeadStoneMarking::default();
+
+        game
+    }
+
+    fn act(game: &mut GoGame, player: GoPlayer, action: ScoringAction) -> ScoringPhaseOutput {
+        game.scoring_phase(ScoringPhaseInput { player, action })
+    }
+
+    #[test]
+    fn test_not_being_scored() {
+        let mut game = GoGame::new(9);
+
+        let output = act(&mut game, GoPlayer::BLACK, ScoringAction::Accept);
+
+        let expected_output = ScoringPhaseOutput {
+            success: false,
+            status: GameStatus::InProgress,
+            dead_stones: Vec::new(),
+            score: None,
+            error: Some(ScoringPhaseError::NotScoring)
+        };
+
+        assert_eq!(expected_output, output);
+    }
+
+    #[test]
+    fn test_marking_starts_from_estimate() {
+        let mut game = game_being_scored();
+
+        assert_eq!(vec![(0, 0), (2, 3)], game.dead_stone_marking.sorted_dead_stones());
+
+        let output = act(&mut game, GoPlayer::WHITE, ScoringAction::ToggleGroup { row: 2, col: 3 });
+        assert_eq!(vec![(0, 0)], output.dead_stones);
+    }
+
+    #[test]
+    fn test_toggle_marks_whole_group() {
+        let mut game = game_being_scored_unmarked();
+        assert_eq!(GameStatus::Scoring, game.status);
+
+        let output = act(&mut game, GoPlayer::WHITE, ScoringAction::ToggleGroup { row: 0, col: 0 });
+        assert!(output.success);
+        assert_eq!(vec![(0, 0)], output.dead_stones);
+
+        let output = act(&mut game, GoPlayer::BLACK, ScoringAction::ToggleGroup { row: 3, col: 2 });
+        assert_eq!(vec![(0, 0), (0, 2), (1, 2), (2, 2), (3, 2)], output.dead_stones);
+
+        let output = act(&mut game, GoPlayer::BLACK, ScoringAction::ToggleGroup { row: 1, col: 2 });
+        assert_eq!(vec![(0, 0)], output.dead_stones);
+    }
+
+    #[test]
+    fn test_toggle_needs_a_stone() {
+        let mut game = game_being_scored_unmarked();
+
+        let output = act(&mut game, GoPlayer::BLACK, ScoringAction::ToggleGroup { row: 1, col: 0 });
+        assert!(!output.success);
+        assert_eq!(Some(ScoringPhaseError::NoStone { row: 1, col: 0 }), output.error);
+
+        let output = act(&mut game, GoPlayer::BLACK, ScoringAction::ToggleGroup { row: 4, col: 0 });
+        assert!(!output.success);
+        assert_eq!(Some(ScoringPhaseError::OutOfBounds { row: 4, col: 0 }), output.error);
+    }
+
+    #[test]
+    fn test_both_accept_finishes_game() {
+        let mut game = game_being_scored_unmarked();
+        game.komi = 0.5;
+        game.scoring_method = ScoringMethod::Area;
+
+        act(&mut game, GoPlayer::BLACK, ScoringAction::ToggleGroup { row: 0, col: 0 });
+        act(&mut game, GoPlayer::WHITE, ScoringAction::ToggleGroup { row: 2, col: 3 });
+        let output = act(&mut game, GoPlayer::BLACK, ScoringAction::Accept);
+        assert_eq!(GameStatus::Scoring, output.status);
+        assert_eq!(None, output.score);
+
+        let output = act(&mut game, GoPlayer::WHITE, ScoringAction::Accept);
+
+        //Black: 5 stones and 3 points, White: 4 stones, 4 points and komi
+        let score = ScoreResult::new(8.0, 8.5);
+        let result = GameResult::Score { winner: GoPlayer::WHITE, margin: 0.5 };
+
+        assert_eq!(Some(score), output.score);
+        assert_eq!(GameStatus::Finished { result }, output.status);
+        assert_eq!(Some(result), game.result());
+        assert_eq!(Some(String::from("W+0.5")), game.game_info.result);
+    }
+
+    #[test]
+    fn test_changing_marking_withdraws_acceptance() {
+        let mut game = game_being_scored_unmarked();
+
+        act(&mut game, GoPlayer::BLACK, ScoringAction::Accept);
+        act(&mut game, GoPlayer::WHITE, ScoringAction::ToggleGroup { row: 0, col: 0 });
+        let output = act(&mut game, GoPlayer::WHITE, ScoringAction::Accept);
+
+        assert_eq!(GameStatus::Scoring, output.status);
+
+        let output = act(&mut game, GoPlayer::BLACK, ScoringAction::Accept);
+        assert!(matches!(output.status, GameStatus::Finished { .. }));
+    }
+
+    #[test]
+    fn test_resume_play() {
+        let mut game = game_being_scored_unmarked();
+        act(&mut game, GoPlayer::BLACK, ScoringAction::ToggleGroup { row: 0, col: 0 });
+
+        let output = act(&mut game, GoPlayer::WHITE, ScoringAction::ResumePlay);
+
+        let expected_output = ScoringPhaseOutput {
+            success: true,
+            status: GameStatus::InProgress,
+            dead_stones: Vec::new(),
+            score: None,
+            error: None
+        };
+
+        assert_eq!(expected_output, output);
+        assert_eq!(GoPlayer::WHITE, game.current_player);
+
+        //The passes before resuming don't count, so it takes two more
+        let output = game.pass_turn(PassTurnInput { player: GoPlayer::WHITE });
+        assert!(!output.game_over);
+        let output = game.pass_turn(PassTurnInput { player: GoPlayer::BLACK });
+        assert!(output.game_over);
+        assert_eq!(GameStatus::Scoring, game.status);
+    }
+
+    #[test]
+    fn test_undo_past_resumption() {
+        let mut game = game_being_scored_unmarked();
+        act(&mut game, GoPlayer::WHITE, ScoringAction::ResumePlay);
+        assert_eq!(Some(13), game.resumed_at);
+
+        game.undo_move(UndoMoveInput);
+        assert_eq!(None, game.resumed_at);
+        assert_eq!(vec![(GoPlayer::WHITE, GoMove::Pass)], game.moves()[11..].to_vec());
+
+        let output = game.pass_turn(PassTurnInput { player: GoPlayer::BLACK });
+        assert!(output.game_over);
+    }
+
+    #[test]
+    fn test_undo_second_pass_clears_marking() {
+        let mut game = game_being_scored_unmarked();
+        act(&mut game, GoPlayer::BLACK, ScoringAction::ToggleGroup { row: 0, col: 0 });
+        act(&mut game, GoPlayer::BLACK, ScoringAction::Accept);
+
+        game.undo_move(UndoMoveInput);
+
+        assert_eq!(GameStatus::InProgress, game.status);
+        assert_eq!(DeadStoneMarking::default(), game.dead_stone_marking);
+
+        //Passing again starts scoring afresh from the estimate
+        game.pass_turn(PassTurnInput { player: GoPlayer::BLACK });
+        assert_eq!(vec![(0, 0), (2, 3)], game.dead_stone_marking.sorted_dead_stones());
+        assert!(game.dead_stone_marking.accepted_by.is_empty());
+    }
+}
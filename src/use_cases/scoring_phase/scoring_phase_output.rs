@@ -0,0 +1,13 @@
+use crate::domain::game_status::GameStatus;
+use crate::domain::scoring::ScoreResult;
+
+use super::scoring_phase_error::ScoringPhaseError;
+
+#[derive(PartialEq, Debug)]
+pub struct ScoringPhaseOutput {
+    pub success: bool,
+    pub status: GameStatus,
+    pub dead_stones: Vec<(u32, u32)>,
+    pub score: Option<ScoreResult>,
+    pub error: Option<ScoringPhaseError>
+}
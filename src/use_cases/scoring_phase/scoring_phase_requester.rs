@@ -0,0 +1,5 @@
+use super::{scoring_phase_output::ScoringPhaseOutput, scoring_phase_input::ScoringPhaseInput};
+
+pub trait ScoringPhaseRequester {
+    fn scoring_phase(&mut self, scoring_phase_input: ScoringPhaseInput) -> ScoringPhaseOutput;
+}
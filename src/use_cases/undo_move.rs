@@ -0,0 +1,6 @@
+pub mod undo_move_input;
+pub mod undo_move_output;
+pub mod undo_move_error;
+
+pub mod undo_move_requester;
+pub mod undo_move_interactor;
\ No newline at end of file
@@ -0,0 +1,38 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum UndoMoveError {
+    GameOver,
+    NoMoves,
+    /// Undo only takes back moves, so it stops at a setup made since the
+    /// last one.
+    SetUpSinceLastMove
+}
+
+impl fmt::Display for UndoMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UndoMoveError::GameOver => write!(f, "The game is over."),
+            UndoMoveError::NoMoves => write!(f, "There are no moves to undo."),
+            UndoMoveError::SetUpSinceLastMove => write!(f, "The board has been set up since the last move.")
+        }
+    }
+}
+
+impl Error for UndoMoveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!("The game is over.", UndoMoveError::GameOver.to_string());
+        assert_eq!("There are no moves to undo.", UndoMoveError::NoMoves.to_string());
+        assert_eq!(
+            "The board has been set up since the last move.",
+            UndoMoveError::SetUpSinceLastMove.to_string()
+        );
+    }
+}
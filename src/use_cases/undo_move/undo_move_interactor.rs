@@ -0,0 +1,245 @@
+use crate::domain::go_game::GoGame;
+
+use super::undo_move_error::UndoMoveError;
+use super::undo_move_input::UndoMoveInput;
+use super::undo_move_requester::UndoMoveRequester;
+use super::undo_move_output::UndoMoveOutput;
+
+impl UndoMoveRequester for GoGame {
+    fn undo_move(&mut self, _undo_move_input: UndoMoveInput) -> UndoMoveOutput {
+        // A finished game keeps its result, whereas a game that is only
+        // waiting to be scored can be taken back to play.
+        let error = if self.result().is_some() {
+            Some(UndoMoveError::GameOver)
+        } else if self.move_history.is_empty() {
+            Some(UndoMoveError::NoMoves)
+        } else if self.setup_follows_last_move() {
+            Some(UndoMoveError::SetUpSinceLastMove)
+        } else {
+            None
+        };
+        if let Some(error) = error {
+            return UndoMoveOutput {
+                success: false,
+                board_state: Some(self.board.board_state().clone()),
+                next_player: Some(self.current_player),
+                undone_move: None,
+                prisoners: self.prisoners.clone(),
+                error: Some(error)
+            };
+        }
+
+        let record = self.unapply().unwrap();
+
+        let undone_move = (record.player, record.chosen_move);
+        self.undone_moves.push(record);
+
+        UndoMoveOutput {
+            success: true,
+            board_state: Some(self.board.board_state().clone()),
+            next_player: Some(self.current_player),
+            undone_move: Some(undone_move),
+            prisoners: self.prisoners.clone(),
+            error: None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use proptest::prelude::*;
+
+    use crate::domain::game_status::GameStatus;
+    use crate::domain::go_board::{GoBoard, GoPlayer};
+    use crate::domain::go_game::{GoMove, KoRule};
+    use crate::domain::setup::Setup;
+    use crate::use_cases::pass_turn::pass_turn_input::PassTurnInput;
+    use crate::use_cases::pass_turn::pass_turn_requester::PassTurnRequester;
+    use crate::use_cases::place_piece::place_piece_input::PlacePieceInput;
+    use crate::use_cases::place_piece::place_piece_requester::PlacePieceRequester;
+
+    use super::*;
+
+    #[test]
+    fn test_undo_without_moves() {
+        let mut game = GoGame::new(9);
+
+        let output = game.undo_move(UndoMoveInput);
+
+        let expected_output = UndoMoveOutput {
+            success: false,
+            board_state: Some(GoBoard::new(9).board_state().clone()),
+            next_player: Some(GoPlayer::BLACK),
+            undone_move: None,
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]),
+            error: Some(UndoMoveError::NoMoves)
+        };
+
+        assert_eq!(expected_output, output);
+    }
+
+    #[test]
+    fn test_undo_capture_restores_stones() {
+        let mut game = GoGame::new(2);
+        game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 1,
+            player: GoPlayer::BLACK
+        });
+        game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 0,
+            player: GoPlayer::WHITE
+        });
+
+        let board_before_capture = game.board.clone();
+        let two_previous_states_before_capture = game.two_previous_states.clone();
+        let position_history_before_capture = game.position_history.clone();
+
+        game.place_piece(PlacePieceInput {
+            row: 1,
+            col: 0,
+            player: GoPlayer::BLACK
+        });
+
+        let output = game.undo_move(UndoMoveInput);
+
+        let expected_output = UndoMoveOutput {
+            success: true,
+            board_state: Some(board_before_capture.board_state().clone()),
+            next_player: Some(GoPlayer::BLACK),
+            undone_move: Some((GoPlayer::BLACK, GoMove::Place(1, 0))),
+            prisoners: HashMap::from([(GoPlayer::BLACK, 0), (GoPlayer::WHITE, 0)]),
+            error: None
+        };
+
+        assert_eq!(expected_output, output);
+        assert_eq!(board_before_capture, game.board);
+        assert_eq!(two_previous_states_before_capture, game.two_previous_states);
+        assert_eq!(position_history_before_capture, game.position_history);
+        assert_eq!(1, game.undone_moves.len());
+    }
+
+    #[test]
+    fn test_undo_suicide_restores_own_group() {
+        let mut game = GoGame::new(3);
+        game.suicide_allowed = true;
+
+        let moves = [
+            (0, 1, GoPlayer::BLACK),
+            (2, 2, GoPlayer::WHITE),
+            (1, 0, GoPlayer::BLACK),
+        ];
+
+        for (row, col, player) in moves {
+            game.place_piece(PlacePieceInput { row, col, player });
+        }
+
+        let board_before_suicide = game.board.clone();
+
+        game.place_piece(PlacePieceInput {
+            row: 0,
+            col: 0,
+            player: GoPlayer::WHITE
+        });
+        assert_eq!(1, game.prisoners[&GoPlayer::BLACK]);
+
+        game.undo_move(UndoMoveInput);
+
+        assert_eq!(board_before_suicide, game.board);
+        assert_eq!(0, game.prisoners[&GoPlayer::BLACK]);
+        assert_eq!(GoPlayer::WHITE, game.current_player);
+    }
+
+    #[test]
+    fn test_undo_second_pass_resumes_game() {
+        let mut game = GoGame::new(9);
+        game.pass_turn(PassTurnInput {
+            player: GoPlayer::BLACK
+        });
+        game.pass_turn(PassTurnInput {
+            player: GoPlayer::WHITE
+        });
+        assert_eq!(GameStatus::Scoring, game.status);
+
+        let output = game.undo_move(UndoMoveInput);
+
+        assert!(output.success);
+        assert_eq!(Some((GoPlayer::WHITE, GoMove::Pass)), output.undone_move);
+        assert!(!game.is_over());
+        assert_eq!(GoPlayer::WHITE, game.current_player);
+        assert_eq!(vec![(GoPlayer::BLACK, GoMove::Pass)], game.moves());
+    }
+
+    #[test]
+    fn test_undo_restores_superko_history() {
+        let mut game = GoGame::new(3);
+        game.ko_rule = KoRule::PositionalSuperko;
+
+        game.place_piece(PlacePieceInput {
+            row: 1,
+            col: 1,
+            player: GoPlayer::BLACK
+        });
+        game.undo_move(UndoMoveInput);
+
+        let output = game.place_piece(PlacePieceInput {
+            row: 1,
+            col: 1,
+            player: GoPlayer::BLACK
+        });
+
+        assert!(output.success);
+    }
+
+    #[test]
+    fn test_undo_stops_at_setup() {
+        let mut game = GoGame::new(5);
+        game.place_piece(PlacePieceInput {
+            row: 2,
+            col: 2,
+            player: GoPlayer::BLACK
+        });
+        game.apply_setup(Setup {
+            white_stones: vec![(0, 0)],
+            ..Setup::default()
+        })
+        .unwrap();
+        let board_after_setup = game.board.clone();
+
+        let output = game.undo_move(UndoMoveInput);
+
+        assert!(!output.success);
+        assert_eq!(Some(UndoMoveError::SetUpSinceLastMove), output.error);
+        assert_eq!(board_after_setup, game.board);
+        assert_eq!(1, game.setup_history.len());
+        assert_eq!(1, game.move_history.len());
+    }
+
+    proptest! {
+        #[test]
+        fn test_undo_all_moves_restores_new_game(
+            suicide_allowed in any::<bool>(),
+            moves in prop::collection::vec((0u32..4, 0u32..4), 0..60)
+        ) {
+            let mut game = GoGame::new(4);
+            game.suicide_allowed = suicide_allowed;
+            let new_game = game.clone();
+
+            for (row, col) in moves {
+                let player = game.current_player;
+                game.place_piece(PlacePieceInput { row, col, player });
+            }
+
+            while game.undo_move(UndoMoveInput).success {}
+
+            prop_assert_eq!(&new_game.board, &game.board);
+            prop_assert_eq!(&new_game.two_previous_states, &game.two_previous_states);
+            prop_assert_eq!(&new_game.position_history, &game.position_history);
+            prop_assert_eq!(&new_game.prisoners, &game.prisoners);
+            prop_assert_eq!(new_game.current_player, game.current_player);
+        }
+    }
+}
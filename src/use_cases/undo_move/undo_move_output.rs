@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+use crate::domain::go_board::{GoBoardState, GoPlayer};
+use crate::domain::go_game::GoMove;
+
+use super::undo_move_error::UndoMoveError;
+
+#[derive(PartialEq, Debug)]
+pub struct UndoMoveOutput {
+    pub success: bool,
+    pub board_state: Option<GoBoardState>,
+    pub next_player: Option<GoPlayer>,
+    pub undone_move: Option<(GoPlayer, GoMove)>,
+    pub prisoners: HashMap<GoPlayer, u32>,
+    pub error: Option<UndoMoveError>
+}
@@ -0,0 +1,5 @@
+use super::{undo_move_output::UndoMoveOutput, undo_move_input::UndoMoveInput};
+
+pub trait UndoMoveRequester {
+    fn undo_move(&mut self, undo_move_input: UndoMoveInput) -> UndoMoveOutput;
+}